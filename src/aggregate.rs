@@ -0,0 +1,165 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Running sum, minimum, and maximum over a numeric stream, kept up to date as elements are computed.
+//! Each prefix is folded at most once, so `sum_to`, `min_to`, and `max_to` are O(1) for anything already reached.
+
+use crate::{indexed::Indexed, Reiterator};
+use ::alloc::vec::Vec;
+use core::ops::{Add, Range, Sub};
+
+/// Sum, minimum, and maximum of every element up to and including some index.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Prefix<Item> {
+    /// Sum of every element so far.
+    pub sum: Item,
+    /// Smallest element so far (the first, if some are incomparable).
+    pub min: Item,
+    /// Largest element so far (the first, if some are incomparable).
+    pub max: Item,
+}
+
+/// View of the running aggregates at each index.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct Aggregates<I: Iterator> {
+    /// Cached elements.
+    iter: Reiterator<I>,
+
+    /// Aggregates folded so far, one per index, always a prefix of the whole sequence.
+    prefixes: Vec<Prefix<I::Item>>,
+
+    /// Safe to edit! See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<I: Iterator> Aggregates<I>
+where
+    I::Item: Clone + PartialOrd + Add<Output = I::Item>,
+{
+    /// Return the aggregates over elements `0..=index`, folding (and computing) any elements not yet reached.
+    /// `None` if `index` is out of bounds, or if an element we still needed to fold has been evicted.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&Prefix<I::Item>> {
+        while self.prefixes.len() <= index {
+            let item = self.iter.at(self.prefixes.len())?.clone();
+            let prefix = match self.prefixes.last() {
+                None => Prefix {
+                    sum: item.clone(),
+                    min: item.clone(),
+                    max: item,
+                },
+                Some(last) => Prefix {
+                    sum: last.sum.clone() + item.clone(),
+                    min: if item < last.min {
+                        item.clone()
+                    } else {
+                        last.min.clone()
+                    },
+                    max: if item > last.max {
+                        item
+                    } else {
+                        last.max.clone()
+                    },
+                },
+            };
+            self.prefixes.push(prefix);
+        }
+        self.prefixes.get(index)
+    }
+
+    /// Sum of elements `0..=index`.
+    #[inline(always)]
+    #[must_use]
+    pub fn sum_to(&mut self, index: usize) -> Option<&I::Item> {
+        self.at(index).map(|prefix| &prefix.sum)
+    }
+
+    /// Smallest of elements `0..=index`.
+    #[inline(always)]
+    #[must_use]
+    pub fn min_to(&mut self, index: usize) -> Option<&I::Item> {
+        self.at(index).map(|prefix| &prefix.min)
+    }
+
+    /// Largest of elements `0..=index`.
+    #[inline(always)]
+    #[must_use]
+    pub fn max_to(&mut self, index: usize) -> Option<&I::Item> {
+        self.at(index).map(|prefix| &prefix.max)
+    }
+
+    /// Return the current aggregates, provided they're in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&mut self) -> Option<Indexed<'_, Prefix<I::Item>>> {
+        let index = self.index;
+        Some(Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+
+    /// Like `Iterator::next` but with a dependent lifetime.
+    #[inline]
+    pub fn next(&mut self) -> Option<Indexed<'_, Prefix<I::Item>>> {
+        let index = self.index;
+        self.index = index.checked_add(1)?;
+        Some(Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+}
+
+impl<I: Iterator> Aggregates<I>
+where
+    I::Item: Clone + PartialOrd + Add<Output = I::Item> + Sub<Output = I::Item>,
+{
+    /// Sum of the elements in `range` (which must be nonempty), as a difference of two prefix sums.
+    #[inline]
+    #[must_use]
+    pub fn sum_range(&mut self, range: Range<usize>) -> Option<I::Item> {
+        let last = range.end.checked_sub(1)?;
+        if range.start > last {
+            return None;
+        }
+        let through = self.sum_to(last)?.clone();
+        match range.start.checked_sub(1) {
+            None => Some(through),
+            Some(before) => Some(through - self.sum_to(before)?.clone()),
+        }
+    }
+}
+
+impl<I: Iterator> Aggregates<I> {
+    /// Set the index to zero. Never refolds anything.
+    #[inline(always)]
+    pub fn restart(&mut self) {
+        self.index = 0;
+    }
+
+    /// Return the underlying `Reiterator`, with every element this view computed still cached (but the aggregates dropped).
+    #[inline(always)]
+    #[must_use]
+    pub fn into_inner(self) -> Reiterator<I> {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// View of the running sum, minimum, and maximum at each index, folded once as elements are reached.
+    #[inline(always)]
+    #[must_use]
+    pub const fn aggregates(self) -> Aggregates<I> {
+        Aggregates {
+            iter: self,
+            prefixes: Vec::new(),
+            index: 0,
+        }
+    }
+}
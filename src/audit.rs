@@ -0,0 +1,119 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Runtime tripwire for the guarantee that cached elements never move in memory.
+//! Only compiled with the `address-audit` feature: every access walks the whole cache, so keep it out of release builds.
+
+use ::alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+
+/// Record of the address of every cached element, checked against the live cache on every access.
+/// Carries no information about the cache's _value_: all audits compare equal, hash to nothing, and clone to an empty record.
+#[derive(Debug, Default)]
+pub struct AddressAudit {
+    /// Address of each element the first time we saw it, or `None` if not (or no longer) cached.
+    addresses: Vec<Option<usize>>,
+    /// Address of each element waiting past the end of the cache (see `Cache::with_segments`/`Cache::get_back`), by index.
+    /// Once cached, it has to be in the same place.
+    seeded: BTreeMap<usize, usize>,
+}
+
+impl AddressAudit {
+    /// Start a new audit with nothing recorded.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            addresses: Vec::new(),
+            seeded: BTreeMap::new(),
+        }
+    }
+
+    /// Compare every cached and seeded element against its recorded address, recording any we haven't seen yet.
+    /// # Panics
+    /// If any cached or seeded element is not where it was the last time we looked
+    /// (including a seeded element that has since been cached).
+    #[inline]
+    pub fn verify<Item>(
+        &mut self,
+        slots: &[Option<Box<Item>>],
+        seeded: &BTreeMap<usize, Box<Item>>,
+    ) {
+        self.addresses.truncate(slots.len());
+        self.addresses.resize(slots.len(), None);
+        for (index, (slot, recorded)) in slots.iter().zip(&mut self.addresses).enumerate() {
+            let live = slot.as_deref().map(address);
+            let then = recorded.or_else(|| live.and(self.seeded.get(&index).copied()));
+            match (then, live) {
+                (Some(then), Some(now)) if then != now => panic!(
+                    "Address audit failed: cached element #{index:} moved from {then:#x} to {now:#x}"
+                ),
+                _ => *recorded = live,
+            }
+        }
+        let recorded = core::mem::take(&mut self.seeded);
+        for (&index, boxed) in seeded {
+            let now = address(&**boxed);
+            match recorded.get(&index) {
+                Some(&then) if then != now => panic!(
+                    "Address audit failed: seeded element #{index:} moved from {then:#x} to {now:#x}"
+                ),
+                _ => drop(self.seeded.insert(index, now)),
+            }
+        }
+    }
+
+    /// Every seeded element just moved back one index (see `Cache::push_front_of_source`), so move their records too.
+    #[inline]
+    pub(crate) fn shift_seeded(&mut self) {
+        self.seeded = core::mem::take(&mut self.seeded)
+            .into_iter()
+            .map(|(index, then)| (index.saturating_add(1), then))
+            .collect();
+    }
+}
+
+/// Where `item` lives, as a plain number to record.
+#[inline(always)]
+fn address<Item>(item: &Item) -> usize {
+    let pointer: *const Item = item;
+    pointer as usize
+}
+
+impl Clone for AddressAudit {
+    /// Addresses belong to the original cache, so a clone starts from scratch.
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for AddressAudit {
+    #[inline(always)]
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for AddressAudit {}
+
+impl PartialOrd for AddressAudit {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AddressAudit {
+    #[inline(always)]
+    fn cmp(&self, _: &Self) -> core::cmp::Ordering {
+        core::cmp::Ordering::Equal
+    }
+}
+
+impl core::hash::Hash for AddressAudit {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, _: &mut H) {}
+}
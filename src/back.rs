@@ -0,0 +1,40 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Reading from the end of a double-ended, exact-size source without computing everything before it.
+//! Elements pulled from the back share the same indices (and the same cache) as those computed from the front.
+
+use crate::{indexed::Indexed, Reiterator};
+
+impl<I: DoubleEndedIterator + ExactSizeIterator> Reiterator<I> {
+    /// Total number of elements (including any evicted), computed or not.
+    #[inline(always)]
+    #[must_use]
+    pub fn exact_len(&self) -> usize {
+        self.cache.exact_len()
+    }
+
+    /// Return the element `from_end` places before the last, pulling it (and everything after it) from the back of the source
+    /// if we haven't computed it yet. Doesn't move either cursor.
+    #[inline(always)]
+    #[must_use]
+    pub fn at_back(&mut self, from_end: usize) -> Option<&I::Item> {
+        self.cache.get_back(from_end)
+    }
+
+    /// Like `DoubleEndedIterator::next_back`, but with a dependent lifetime, and with the element's index from the front.
+    /// Moves only `back_index`, so it never stops at the front cursor: the two ends read independently.
+    #[inline]
+    pub fn next_back(&mut self) -> Option<Indexed<'_, I::Item>> {
+        let from_end = self.back_index;
+        let index = self.exact_len().checked_sub(from_end)?.checked_sub(1)?;
+        self.back_index = from_end.checked_add(1)?;
+        Some(Indexed {
+            index,
+            value: self.cache.get_back(from_end)?,
+        })
+    }
+}
@@ -0,0 +1,96 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Binary search over sources known to be sorted, computing only O(log n) more elements than the answer needs.
+//! We gallop (probe indices 0, 1, 3, 7, ...) until we overshoot the target, then bisect what's in between.
+
+use crate::{indexed::Indexed, Reiterator};
+use core::cmp::Ordering;
+
+impl<I: Iterator> Reiterator<I> {
+    /// Compare the first non-evicted element at or after `index` (computing it if we haven't), along with its index.
+    /// `None` if the source ends first.
+    #[inline]
+    fn compare_from<Compare: FnMut(&I::Item) -> Ordering>(
+        &mut self,
+        mut index: usize,
+        compare: &mut Compare,
+    ) -> Option<(usize, Ordering)> {
+        loop {
+            if let Some(item) = self.at(index) {
+                return Some((index, compare(item)));
+            }
+            if !self.cache.is_evicted(index) {
+                return None;
+            }
+            index = index.checked_add(1)?;
+        }
+    }
+
+    /// Like `slice::binary_search_by`, for sources whose elements are sorted consistently with `compare`
+    /// (which says how each element compares to the target). Computes only as far as the target (plus a galloping overshoot).
+    /// Evicted elements are stepped over. The cursor is left alone.
+    /// # Errors
+    /// If nothing matches, the index at which the target could be inserted to keep everything sorted.
+    #[inline]
+    pub fn binary_search_by<Compare: FnMut(&I::Item) -> Ordering>(
+        &mut self,
+        mut compare: Compare,
+    ) -> Result<Indexed<'_, I::Item>, usize> {
+        // Everything before `lo` is `Less`; the target, if anywhere, is before `hi`.
+        let mut lo = 0_usize;
+        let mut width = 1_usize;
+        let mut hi = loop {
+            let probe = lo.saturating_add(width).saturating_sub(1);
+            match self.compare_from(probe, &mut compare) {
+                None => break self.cached_len(),
+                Some((index, Ordering::Less)) => {
+                    lo = index.saturating_add(1);
+                    width = width.saturating_mul(2);
+                }
+                Some((index, _)) => break index.saturating_add(1),
+            }
+        };
+        while lo < hi {
+            let mid = lo.wrapping_add(hi.wrapping_sub(lo) / 2);
+            match self.compare_from(mid, &mut compare) {
+                Some((index, Ordering::Less)) if index < hi => lo = index.wrapping_add(1),
+                Some((index, Ordering::Equal)) if index < hi => {
+                    return Ok(Indexed {
+                        index,
+                        value: self.cache.cached(index).ok_or(index)?,
+                    });
+                }
+                Some((index, Ordering::Greater)) if index < hi => hi = index,
+                _ => hi = mid,
+            }
+        }
+        Err(lo)
+    }
+
+    /// Like `slice::binary_search`, for sorted sources. See `binary_search_by`.
+    /// # Errors
+    /// If nothing matches, the index at which `target` could be inserted to keep everything sorted.
+    #[inline(always)]
+    pub fn binary_search(&mut self, target: &I::Item) -> Result<Indexed<'_, I::Item>, usize>
+    where
+        I::Item: Ord,
+    {
+        self.binary_search_by(|item| item.cmp(target))
+    }
+
+    /// Like `slice::binary_search_by_key`, for sources sorted by `key`. See `binary_search_by`.
+    /// # Errors
+    /// If nothing matches, the index at which an element with key `target` could be inserted to keep everything sorted.
+    #[inline(always)]
+    pub fn binary_search_by_key<Key: Ord, KeyFn: FnMut(&I::Item) -> Key>(
+        &mut self,
+        target: &Key,
+        mut key: KeyFn,
+    ) -> Result<Indexed<'_, I::Item>, usize> {
+        self.binary_search_by(|item| key(item).cmp(target))
+    }
+}
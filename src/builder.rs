@@ -0,0 +1,162 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Every setting a `Reiterator` takes, in one place, before anything is computed.
+//! Each one is also available after construction (e.g. `set_growth`, `set_probe`); this just saves bolting them on one by one.
+
+use crate::{
+    cache::{Cache, Growth, Overflow, Probe},
+    subscribe::{Callback, Observer},
+    Reiterator,
+};
+use ::alloc::vec::Vec;
+use core::num::NonZeroUsize;
+
+/// Settings for a `Reiterator` that hasn't been built yet. Start with `Reiterator::builder`.
+#[allow(missing_debug_implementations)]
+pub struct ReiteratorBuilder<I: Iterator> {
+    /// Source, not yet touched.
+    iter: I,
+
+    /// Elements to make room for up front.
+    capacity: usize,
+
+    /// How storage grows. See `Reiterator::set_growth`.
+    growth: Growth,
+
+    /// Most elements to hold at once, and what to do about more. See `Reiterator::set_max_cached`.
+    max_cached: Option<(usize, Overflow)>,
+
+    /// How hard cursor reads try. See `Reiterator::set_probe`.
+    probe: Probe,
+
+    /// How far `next` moves at a time. See `Reiterator::step_by`.
+    stride: NonZeroUsize,
+
+    /// Block length and callback for each subscriber. See `Reiterator::subscribe`.
+    subscriptions: Vec<(usize, Callback<I::Item>)>,
+
+    /// How many of the most recent elements to keep. See `Reiterator::set_window`.
+    window: Option<NonZeroUsize>,
+
+    /// Reads to observe before locking in a retention window. See `Reiterator::auto_tune`.
+    #[cfg(feature = "profiling")]
+    auto_tune: Option<usize>,
+}
+
+impl<I: Iterator> ReiteratorBuilder<I> {
+    /// Make room for `capacity` elements before the cache has to grow. See `Reiterator::with_capacity`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Choose how storage grows when it runs out of room. See `Reiterator::set_growth`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn growth(mut self, growth: Growth) -> Self {
+        self.growth = growth;
+        self
+    }
+
+    /// Never hold more than `max_cached` elements at once, doing `overflow` instead. See `Reiterator::set_max_cached`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn max_cached(mut self, max_cached: usize, overflow: Overflow) -> Self {
+        self.max_cached = Some((max_cached, overflow));
+        self
+    }
+
+    /// Choose how hard `get`/`next` try to compute elements past the end of the cache. See `Reiterator::set_probe`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn probe(mut self, probe: Probe) -> Self {
+        self.probe = probe;
+        self
+    }
+
+    /// Make `next` advance the cursor `step` elements at a time. See `Reiterator::step_by`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn step_by(mut self, step: NonZeroUsize) -> Self {
+        self.stride = step;
+        self
+    }
+
+    /// Call `callback` with every block of `block_len` consecutive elements, from the very first. See `Reiterator::subscribe`.
+    #[inline]
+    #[must_use]
+    pub fn subscribe<Subscriber: Observer<I::Item>>(
+        mut self,
+        block_len: usize,
+        callback: Subscriber,
+    ) -> Self {
+        self.subscriptions
+            .push((block_len, Callback::new(callback)));
+        self
+    }
+
+    /// Keep only the `window` most recently computed elements. See `Reiterator::set_window`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn window(mut self, window: NonZeroUsize) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Watch the first `observe` reads, then lock in a growth policy and eviction window. See `Reiterator::auto_tune`.
+    #[cfg(feature = "profiling")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn auto_tune(mut self, observe: usize) -> Self {
+        self.auto_tune = Some(observe);
+        self
+    }
+
+    /// Build the `Reiterator`, computing nothing yet.
+    #[inline]
+    #[must_use]
+    pub fn build(self) -> Reiterator<I> {
+        let mut cache = Cache::with_capacity(self.iter, self.capacity);
+        cache.set_growth(self.growth);
+        if let Some((max_cached, overflow)) = self.max_cached {
+            cache.set_max_cached(max_cached, overflow);
+        }
+        cache.set_window(self.window);
+        let mut reiterator = Reiterator::from_cache(cache).step_by(self.stride);
+        reiterator.set_probe(self.probe);
+        for (block_len, callback) in self.subscriptions {
+            reiterator.subscribe_from(0, block_len, callback);
+        }
+        #[cfg(feature = "profiling")]
+        if let Some(observe) = self.auto_tune {
+            reiterator.auto_tune(observe);
+        }
+        reiterator
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Configure a `Reiterator` before building it, e.g. `Reiterator::builder(source).capacity(1024).probe(Probe::CacheOnly).build()`.
+    #[inline(always)]
+    #[must_use]
+    pub fn builder<II: IntoIterator<IntoIter = I>>(into_iter: II) -> ReiteratorBuilder<I> {
+        ReiteratorBuilder {
+            iter: into_iter.into_iter(),
+            capacity: 0,
+            growth: Growth::default(),
+            max_cached: None,
+            probe: Probe::default(),
+            stride: NonZeroUsize::MIN,
+            subscriptions: Vec::new(),
+            window: None,
+            #[cfg(feature = "profiling")]
+            auto_tune: None,
+        }
+    }
+}
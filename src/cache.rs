@@ -5,20 +5,265 @@
  */
 
 //! Cache that only works with iterator-like structures.
-//! This file shouldn't have a single instace of the term `mut` (other than this one lol).
+//! Elements are boxed so they never move once cached: references into the cache stay valid until an element is evicted,
+//! which is why eviction (`cap`, `window`, `retain_cached`, ...) is the only thing callers holding references have to hold off.
 
 #![allow(box_pointers)]
 
-use ::alloc::{vec, vec::Vec};
+#[cfg(feature = "unstable")]
+use crate::indexed::Indexed;
+#[cfg(feature = "unstable")]
+use ::alloc::collections::BTreeMap;
+use ::alloc::{boxed::Box, vec, vec::Vec};
+#[cfg(feature = "unstable")]
+use core::num::NonZeroUsize;
+
+/// Marker recording how many elements a cache had computed at some point in time.
+/// Hand it back to `cached_since`/`delta_since` to see only what's been computed since.
+#[cfg(feature = "unstable")]
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Frontier(usize);
+
+#[cfg(feature = "unstable")]
+impl Frontier {
+    /// Index of the first element computed after this was captured (i.e. how many had been computed at the time).
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Handle to one cached element that can't silently go stale.
+/// Issued by the cache for a computed element; resolves to `None` once that element is evicted or the cache is invalidated,
+/// rather than to whatever happens to live at the same index later.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ElementId {
+    /// Index of the element when the handle was issued.
+    index: usize,
+    /// Cache generation (number of invalidations so far) when the handle was issued.
+    generation: usize,
+}
+
+#[cfg(feature = "unstable")]
+impl ElementId {
+    /// Index of the element this handle refers to.
+    #[inline(always)]
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// Outcome of resolving a batch of `ElementId`s at once.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Resolved<'cache, Item> {
+    /// Every ID that still resolves, with its element, in the order requested.
+    pub live: Vec<(ElementId, &'cache Item)>,
+    /// Every ID whose element has been evicted or invalidated, in the order requested.
+    pub stale: Vec<ElementId>,
+}
+
+/// How hard a cursor-driven read (`Reiterator::get`/`next`) should try when the cursor is past everything cached so far.
+/// Random access (`at`) always computes as far as it needs to.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Probe {
+    /// Compute as many elements as it takes (exhausting the source if the cursor is out of bounds).
+    #[default]
+    Exhaust,
+    /// Compute at most this many new elements per read, then give up with `None`.
+    AtMost(usize),
+    /// Never compute anything new: anything not already cached is `None`.
+    CacheOnly,
+}
+
+/// What to do when computing another element would put more than `max_cached` elements in the cache.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Overflow {
+    /// Stop computing: reads past the last element cached see `None` (`Reiterator::checked_at` says why).
+    #[default]
+    Refuse,
+    /// Panic.
+    Panic,
+    /// Evict the oldest element still cached to make room. Each evicted element still costs a null pointer.
+    Evict,
+}
+
+/// Computing the element asked for would have put more than `max_cached` elements in the cache.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct CacheFull {
+    /// Most elements allowed in the cache at once.
+    pub max_cached: usize,
+    /// Index of the element asked for.
+    pub index: usize,
+}
+
+#[cfg(feature = "unstable")]
+impl core::fmt::Display for CacheFull {
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "computing element #{} would put more than {} elements in the cache",
+            self.index, self.max_cached,
+        )
+    }
+}
+
+#[cfg(all(feature = "std", feature = "unstable"))]
+impl std::error::Error for CacheFull {}
+
+/// Settings that would evict on their own, set aside by `Cache::suspend_eviction` until `Cache::resume_eviction`.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Suspended {
+    /// `cap` as it was.
+    cap: Option<(usize, Overflow)>,
+    /// `window` as it was.
+    window: Option<NonZeroUsize>,
+}
+
+/// Where a stream is in its life, as one `match`able value.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum StreamState {
+    /// Nothing computed yet.
+    Unstarted,
+    /// Some elements computed (including any since evicted), and the source might have more.
+    Partial {
+        /// Number of elements computed so far.
+        cached: usize,
+    },
+    /// The source has ended: this is every element there will ever be.
+    Exhausted {
+        /// Total number of elements.
+        len: usize,
+    },
+    /// The source panicked while computing an element, so nothing past `cached` will ever be computed.
+    Poisoned {
+        /// Number of elements computed before the panic.
+        cached: usize,
+    },
+    /// The last cursor read gave up because of its `Probe` policy, though the source might have more.
+    Budgeted {
+        /// Number of elements computed so far.
+        cached: usize,
+    },
+}
+
+/// How the cache's storage grows when it runs out of room.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Growth {
+    /// Whatever `Vec` does (currently doubling): fewest reallocations, but up to twice the memory you need.
+    #[default]
+    Doubling,
+    /// Room for exactly one more element at a time: no slack at all, but a reallocation on every new element.
+    Exact,
+    /// Room for this many more elements at a time (at least one).
+    Chunk(usize),
+    /// Chunks sized from `size_of::<Item>()` to take up roughly this many bytes (slots plus boxed elements), so tiny elements
+    /// don't reallocate constantly and huge ones don't overshoot. When a request jumps further ahead than one chunk
+    /// (e.g. `at` far past the end, or `exhaust` on a source with a size hint), chunks grow to match, up to 8x the target.
+    Adaptive {
+        /// Target size of each chunk, in bytes.
+        bytes_per_chunk: usize,
+    },
+}
+
+#[cfg(feature = "unstable")]
+impl Growth {
+    /// Number of elements to make room for next, given how many the current request still needs.
+    #[inline]
+    const fn chunk<Item>(self, demand: usize) -> usize {
+        match self {
+            Self::Doubling => 0,
+            Self::Exact => 1,
+            Self::Chunk(chunk) => {
+                if chunk == 0 {
+                    1
+                } else {
+                    chunk
+                }
+            }
+            Self::Adaptive { bytes_per_chunk } => {
+                let per_element = size_of::<Option<Box<Item>>>().saturating_add(size_of::<Item>());
+                let base = bytes_per_chunk / per_element;
+                let base = if base == 0 { 1 } else { base };
+                let cap = base.saturating_mul(8);
+                if demand <= base {
+                    base
+                } else if demand >= cap {
+                    cap
+                } else {
+                    demand
+                }
+            }
+        }
+    }
+}
 
 /// Cache that works with iterator-like structures.
 /// Note that all operations are `const` since there are no user-facing mutations.
-#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Cache<I: Iterator> {
     /// Iterator producing the input being cached.
     iter: I,
-    /// Vector of cached inputs.
-    vec: Vec<I::Item>,
+    /// Vector of cached inputs, each boxed so that an evicted slot (`None`) costs only a null pointer.
+    vec: Vec<Option<Box<I::Item>>>,
+    /// Elements past the end of `vec` we were handed up front (see `with_segments`), waiting for `vec` to reach them.
+    #[cfg(feature = "unstable")]
+    seeded: BTreeMap<usize, Box<I::Item>>,
+    /// Start and end of the indices pulled from the back of the source (see `get_back`), which wait in `seeded` like any other.
+    /// The source no longer produces these, so they're never skipped with `Iterator::nth`.
+    #[cfg(feature = "unstable")]
+    back: Option<(usize, usize)>,
+    /// Total number of elements, once the source has said it's done.
+    known_len: Option<usize>,
+    /// Whether the source panicked (and we caught it), so we never touch it again.
+    #[cfg(feature = "unstable")]
+    poisoned: bool,
+    /// How `vec` grows.
+    #[cfg(feature = "unstable")]
+    growth: Growth,
+    /// Most elements to keep in `vec` at once (not counting tombstones), and what to do about more.
+    #[cfg(feature = "unstable")]
+    cap: Option<(usize, Overflow)>,
+    /// Keep only this many of the most recently computed elements, if set.
+    #[cfg(feature = "unstable")]
+    window: Option<NonZeroUsize>,
+    /// Elements held that haven't been evicted: those in `vec`, plus any pulled from the back (see `back`) still waiting in `seeded`.
+    #[cfg(feature = "unstable")]
+    retained: usize,
+    /// Every element before this has been evicted (not necessarily the only ones), so `Overflow::Evict` starts looking here.
+    #[cfg(feature = "unstable")]
+    oldest: usize,
+    /// Elements from this index on are still owed to a subscriber, so nothing evicts them until they've been handed over.
+    #[cfg(feature = "unstable")]
+    pinned: usize,
+    /// How many more elements the request we're populating for needs (a guess, for `Growth::Adaptive`).
+    #[cfg(feature = "unstable")]
+    demand: usize,
+    /// Length the cache was cut back to at each invalidation, oldest first; see `ElementId`.
+    /// The number of invalidations so far is the current generation.
+    #[cfg(feature = "unstable")]
+    truncations: Vec<usize>,
+    /// Where each cached element lives, verified on every access.
+    #[cfg(feature = "address-audit")]
+    audit: crate::audit::AddressAudit,
 }
 
 impl<I: Iterator> Cache<I> {
@@ -28,30 +273,1008 @@ impl<I: Iterator> Cache<I> {
         Self {
             iter: into_iter.into_iter(),
             vec: vec![],
+            #[cfg(feature = "unstable")]
+            seeded: BTreeMap::new(),
+            #[cfg(feature = "unstable")]
+            back: None,
+            known_len: None,
+            #[cfg(feature = "unstable")]
+            poisoned: false,
+            #[cfg(feature = "unstable")]
+            truncations: Vec::new(),
+            #[cfg(feature = "unstable")]
+            growth: Growth::Doubling,
+            #[cfg(feature = "unstable")]
+            cap: None,
+            #[cfg(feature = "unstable")]
+            window: None,
+            #[cfg(feature = "unstable")]
+            retained: 0,
+            #[cfg(feature = "unstable")]
+            oldest: 0,
+            #[cfg(feature = "unstable")]
+            pinned: usize::MAX,
+            #[cfg(feature = "unstable")]
+            demand: 0,
+            #[cfg(feature = "address-audit")]
+            audit: crate::audit::AddressAudit::new(),
+        }
+    }
+
+    /// Initialize a new empty cache with room for `capacity` elements before reallocating.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn with_capacity<II: IntoIterator<IntoIter = I>>(into_iter: II, capacity: usize) -> Self {
+        let mut cache = Self::new(into_iter);
+        cache.vec = Vec::with_capacity(capacity);
+        cache
+    }
+
+    /// Choose how storage grows when it runs out of room (by default, however `Vec` does).
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn set_growth(&mut self, growth: Growth) {
+        self.growth = growth;
+    }
+
+    /// Number of elements we can cache before storage has to grow again.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// Never hold more than `max_cached` elements (not counting evicted ones) at once, doing `overflow` instead.
+    /// Elements already cached are left alone, even if there are more than `max_cached` of them.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn set_max_cached(&mut self, max_cached: usize, overflow: Overflow) {
+        self.cap = Some((max_cached, overflow));
+    }
+
+    /// Most elements to hold at once, and what happens past that, if there's a limit.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn max_cached(&self) -> Option<(usize, Overflow)> {
+        self.cap
+    }
+
+    /// Keep only the `window` most recently computed elements, evicting each as it falls out of the window
+    /// (starting with anything already cached). `None` to keep everything from now on.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn set_window(&mut self, window: Option<NonZeroUsize>) {
+        self.window = window;
+        self.slide();
+    }
+
+    /// How many of the most recently computed elements we keep, if not all of them.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn window(&self) -> Option<NonZeroUsize> {
+        self.window
+    }
+
+    /// Stop evicting on our own until `resume_eviction`: `Overflow::Evict` acts like `Overflow::Refuse`, and the window stops sliding.
+    /// For when references we've handed out outlive what the borrow checker can see.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub(crate) fn suspend_eviction(&mut self) -> Suspended {
+        let suspended = Suspended {
+            cap: self.cap,
+            window: self.window,
+        };
+        if let Some((max_cached, Overflow::Evict)) = self.cap {
+            self.cap = Some((max_cached, Overflow::Refuse));
+        }
+        self.window = None;
+        suspended
+    }
+
+    /// Put back what `suspend_eviction` set aside. Evicts nothing right away (references handed out may still be live):
+    /// anything owed is evicted by the next element we cache.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub(crate) fn resume_eviction(&mut self, suspended: Suspended) {
+        self.cap = suspended.cap;
+        self.window = suspended.window;
+    }
+
+    /// Keep every element from `index` on until it's been handed to a subscriber (`usize::MAX` if no one's waiting).
+    /// Evicts nothing itself, even if it unpins something: anything overdue is evicted by the next element we cache.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub(crate) fn pin_from(&mut self, index: usize) {
+        self.pinned = index;
+    }
+
+    /// Drop the cap and the window for good, so nothing can stop a read or evict behind it. Evicts nothing itself.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub(crate) fn lift_limits(&mut self) {
+        self.cap = None;
+        self.window = None;
+    }
+
+    /// Evict everything that's fallen out of the window.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    fn slide(&mut self) {
+        let Some(window) = self.window else {
+            return;
+        };
+        let keep_from = self.vec.len().saturating_sub(window.get()).min(self.pinned);
+        while self.oldest < keep_from {
+            if let Some(slot) = self.vec.get_mut(self.oldest) {
+                if slot.take().is_some() {
+                    self.retained = self.retained.wrapping_sub(1);
+                }
+            }
+            self.oldest = self.oldest.wrapping_add(1);
+        }
+        // Anything pulled from the back counts too, and only the front can give way.
+        self.evict_oldest(window.get());
+    }
+
+    /// Whether a read at `index` came back empty because `Overflow::Refuse` stopped us computing it.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn refused(&self, index: usize) -> Option<CacheFull> {
+        match self.cap {
+            Some((max_cached, Overflow::Refuse))
+                if self.retained >= max_cached
+                    && index >= self.vec.len()
+                    && self.known_len.is_none()
+                    && !self.seeded.contains_key(&index) =>
+            {
+                Some(CacheFull { max_cached, index })
+            }
+            Some(_) | None => None,
+        }
+    }
+
+    /// Whether `Overflow::Refuse` could stop us caching more before the source ends.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn may_refuse(&self) -> bool {
+        matches!(self.cap, Some((_, Overflow::Refuse)))
+    }
+
+    /// Get ready to cache the element at `index`, according to `cap`. Returns whether we can.
+    /// # Panics
+    /// If the cache is full and the policy is `Overflow::Panic`.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    fn make_room(&mut self, index: usize) -> bool {
+        let Some((max_cached, overflow)) = self.cap else {
+            return true;
+        };
+        if self.retained < max_cached {
+            return true;
+        }
+        match overflow {
+            Overflow::Refuse => false,
+            Overflow::Panic => panic!(
+                "Cache full: computing element #{index} would put more than {max_cached} elements in the cache",
+            ),
+            Overflow::Evict => {
+                self.evict_oldest(max_cached.saturating_sub(1));
+                // Stopping short of the end of `vec` means the rest is pinned, so go over the cap until it's been handed over.
+                self.retained < max_cached || self.oldest < self.vec.len()
+            }
+        }
+    }
+
+    /// Evict elements from the front, oldest first, until at most `keep` are held (or there's nothing left in `vec` we may evict).
+    #[cfg(feature = "unstable")]
+    #[inline]
+    fn evict_oldest(&mut self, keep: usize) {
+        while self.retained > keep && self.oldest < self.vec.len().min(self.pinned) {
+            if let Some(slot) = self.vec.get_mut(self.oldest) {
+                if slot.take().is_some() {
+                    self.retained = self.retained.wrapping_sub(1);
+                }
+            }
+            self.oldest = self.oldest.wrapping_add(1);
+        }
+    }
+
+    /// Get ready to pull the element at `index` from the back of the source, according to `window` and `cap`. Returns whether we can.
+    /// Only elements at the front can be evicted to make room, so we refuse any pull that would leave no room for the front to move:
+    /// otherwise every step forward would have to evict the element it just computed.
+    /// # Panics
+    /// If the cache is full and the policy is `Overflow::Panic`.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    fn make_room_back(&mut self, index: usize) -> bool {
+        let pending = self.back_pending().saturating_add(1);
+        if let Some(window) = self.window {
+            if pending >= window.get() {
+                return false;
+            }
+            self.evict_oldest(window.get().wrapping_sub(1));
+        }
+        if let Some((max_cached, _)) = self.cap {
+            if pending >= max_cached {
+                return false;
+            }
+        }
+        self.make_room(index)
+    }
+
+    /// Number of elements pulled from the back (see `get_back`) that the front hasn't reached yet, all waiting in `seeded`.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    fn back_pending(&self) -> usize {
+        self.back.map_or(0, |(start, end)| {
+            end.saturating_sub(start.max(self.vec.len()))
+        })
+    }
+
+    /// Make room for at least `additional` more elements past those already cached, e.g. once you know how long the input is.
+    /// Ignores `Growth`, since you asked for a specific amount.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    /// Give back any storage past the elements already cached, e.g. after `truncate`.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn shrink_to_fit(&mut self) {
+        self.vec.shrink_to_fit();
+    }
+
+    /// Append a newly computed element, growing storage according to `self.growth`.
+    /// Takes a box so that seeded elements keep their address.
+    #[inline]
+    fn push(&mut self, boxed: Box<I::Item>) {
+        #[cfg(feature = "unstable")]
+        {
+            if self.vec.len() == self.vec.capacity() {
+                let chunk = self.growth.chunk::<I::Item>(self.demand);
+                if chunk != 0 {
+                    self.vec.reserve_exact(chunk);
+                }
+            }
+            self.demand = self.demand.saturating_sub(1);
+            self.retained = self.retained.wrapping_add(1);
+        }
+        self.vec.push(Some(boxed));
+        #[cfg(feature = "unstable")]
+        self.slide();
+    }
+
+    /// Extend the cache by one element from the source, or by every consecutive seeded element if the next one was seeded
+    /// (skipping past them in the source with `Iterator::nth`). Returns whether we made any progress.
+    #[inline]
+    fn step<Error, Pull: FnMut(&mut I) -> Result<Option<I::Item>, Error>>(
+        &mut self,
+        pull: &mut Pull,
+    ) -> Result<bool, Error> {
+        if self.known_len.is_some() {
+            return Ok(false);
+        }
+        #[cfg(feature = "unstable")]
+        {
+            if self.poisoned {
+                return Ok(false);
+            }
+            if self.step_seeded() {
+                return Ok(true);
+            }
+            if !self.make_room(self.vec.len()) {
+                return Ok(false);
+            }
+        }
+        match pull(&mut self.iter)? {
+            Some(item) => {
+                self.push(Box::new(item));
+                Ok(true)
+            }
+            None => {
+                self.known_len = Some(self.vec.len());
+                Ok(false)
+            }
         }
     }
 
+    /// If the next element was seeded, extend the cache by it and every consecutive seeded element after it
+    /// (skipping past them in the source with `Iterator::nth`). Returns whether we made any progress.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    fn step_seeded(&mut self) -> bool {
+        let mut run = 0_usize;
+        let mut in_source = 0_usize;
+        while self.seeded.contains_key(&self.vec.len()) {
+            let index = self.vec.len();
+            // Elements pulled from the back already count toward `retained`, so moving them over needs no room.
+            let from_back = self.back.is_some_and(|(start, _)| index >= start);
+            if !from_back && !self.make_room(index) {
+                break;
+            }
+            let Some(boxed) = self.seeded.remove(&index) else {
+                break;
+            };
+            if from_back {
+                self.retained = self.retained.wrapping_sub(1);
+            } else {
+                in_source = in_source.saturating_add(1);
+            }
+            self.push(boxed);
+            run = run.saturating_add(1);
+        }
+        if run == 0 {
+            return false;
+        }
+        if let Some(skip) = in_source.checked_sub(1) {
+            drop(self.iter.nth(skip));
+        }
+        true
+    }
+
+    /// Initialize a cache already holding `prefix`, with `into_iter` producing everything after it
+    /// (e.g. to resume from a checkpoint without recomputing what was saved).
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn with_warm_cache<Prefix: IntoIterator<Item = I::Item>, II: IntoIterator<IntoIter = I>>(
+        prefix: Prefix,
+        into_iter: II,
+    ) -> Self {
+        let mut cache = Self::new(into_iter);
+        cache
+            .vec
+            .extend(prefix.into_iter().map(|item| Some(Box::new(item))));
+        cache.retained = cache.vec.len();
+        cache
+    }
+
+    /// Initialize a cache already holding every element in `segments`, each a range of indices and the values for them,
+    /// in any order and with gaps between them. `into_iter` produces the _whole_ sequence, starting from index zero:
+    /// gaps are computed from it on demand, and seeded elements are skipped with `Iterator::nth`
+    /// (so they cost nothing to skip if the source's `nth` is cheap). Later segments overwrite earlier ones where they overlap.
+    /// Seeded elements past the last computed one are visible only to `get` (and friends) until everything before them is computed.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn with_segments<
+        Values: IntoIterator<Item = I::Item>,
+        Segments: IntoIterator<Item = (core::ops::Range<usize>, Values)>,
+        II: IntoIterator<IntoIter = I>,
+    >(
+        segments: Segments,
+        into_iter: II,
+    ) -> Self {
+        let mut cache = Self::new(into_iter);
+        for (range, values) in segments {
+            for (index, value) in range.zip(values) {
+                drop(cache.seeded.insert(index, Box::new(value)));
+            }
+        }
+        cache
+    }
+
     /// Whether this cache holds any cached elements.
     #[inline(always)]
     #[must_use]
-    pub fn is_empty(&self) -> bool {
+    pub const fn is_empty(&self) -> bool {
         self.vec.is_empty()
     }
 
+    /// Number of elements computed so far (including any since evicted).
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn cached_len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Mark how far we've computed so far, to later ask `cached_since` what's new.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn frontier(&self) -> Frontier {
+        Frontier(self.vec.len())
+    }
+
+    /// Every element cached at or after `frontier`, in order, skipping evicted elements.
+    /// Never touches the underlying iterator.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn cached_since(&self, frontier: Frontier) -> impl Iterator<Item = Indexed<'_, I::Item>> {
+        self.cached_range(frontier.0..usize::MAX)
+    }
+
+    /// Every already-cached element with an index in `range`, in order, skipping evicted elements.
+    /// Never touches the underlying iterator.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn cached_range(
+        &self,
+        range: core::ops::Range<usize>,
+    ) -> impl Iterator<Item = Indexed<'_, I::Item>> {
+        let end = range.end.min(self.vec.len());
+        let start = range.start.min(end);
+        self.vec
+            .get(start..end)
+            .unwrap_or_default()
+            .iter()
+            .zip(start..)
+            .filter_map(|(slot, index)| slot.as_deref().map(|value| Indexed { index, value }))
+    }
+
+    /// Whether the source panicked while we were pulling from it; if so, we never will again.
+    /// Never touches the underlying iterator.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_poisoned(&self) -> bool {
+        self.poisoned
+    }
+
+    /// Stop pulling from the source for good (e.g. after it panicked), keeping everything already cached.
+    #[cfg(all(feature = "std", feature = "unstable"))]
+    #[inline(always)]
+    pub(crate) fn poison(&mut self) {
+        self.poisoned = true;
+    }
+
+    /// Total number of elements, if we've already seen the source end.
+    /// Never touches the underlying iterator.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn known_len(&self) -> Option<usize> {
+        self.known_len
+    }
+
+    /// Total number of elements, if we can tell without computing anything: the source has ended,
+    /// we've pulled from its back (see `get_back`), or its `size_hint` is exact.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn len_without_computing(&self) -> Option<usize> {
+        if let Some(len) = self.known_len {
+            return Some(len);
+        }
+        if let Some((_, end)) = self.back {
+            return Some(end);
+        }
+        match self.iter.size_hint() {
+            (lower, Some(upper)) if lower == upper => self.vec.len().checked_add(lower),
+            _ => None,
+        }
+    }
+
+    /// Bounds on how many elements are still to be cached, like `Iterator::size_hint`: whatever the source has left,
+    /// plus anything pulled from its back (see `get_back`) that's waiting in `seeded`. Exact whenever the source's own hint is
+    /// (and zero once it has ended or panicked).
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn uncached_hint(&self) -> (usize, Option<usize>) {
+        // Once the source has ended or panicked, we never pull from it again, whatever it says.
+        if self.known_len.is_some() || self.poisoned {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        let pulled = self.back_pending();
+        (
+            lower.saturating_add(pulled),
+            upper.and_then(|upper| upper.checked_add(pulled)),
+        )
+    }
+
+    /// Stable handle to the element at `index`, provided it's cached (and not evicted).
+    /// Never touches the underlying iterator.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn id(&self, index: usize) -> Option<ElementId> {
+        self.vec.get(index)?.as_ref().map(|_| ElementId {
+            index,
+            generation: self.truncations.len(),
+        })
+    }
+
+    /// The element `id` was issued for, unless it's since been evicted or invalidated.
+    /// Never touches the underlying iterator.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn resolve(&self, id: ElementId) -> Option<&I::Item> {
+        let since = self.truncations.get(id.generation..)?;
+        if since.iter().any(|&len| id.index >= len) {
+            return None;
+        }
+        self.vec.get(id.index)?.as_deref()
+    }
+
+    /// Resolve every ID in `ids`, sorting them into those still live and those gone stale.
+    /// Never touches the underlying iterator.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn resolve_many(&self, ids: &[ElementId]) -> Resolved<'_, I::Item> {
+        let mut resolved = Resolved {
+            live: Vec::new(),
+            stale: Vec::new(),
+        };
+        for &id in ids {
+            match self.resolve(id) {
+                Some(value) => resolved.live.push((id, value)),
+                None => resolved.stale.push(id),
+            }
+        }
+        resolved
+    }
+
+    /// The element at `index`, if it's already cached (and not evicted).
+    /// Never touches the underlying iterator.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub fn cached(&self, index: usize) -> Option<&I::Item> {
+        self.vec.get(index)?.as_deref()
+    }
+
+    /// The iterator producing the input being cached.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn source(&self) -> &I {
+        &self.iter
+    }
+
+    /// Whether the element at `index` was computed but has since been evicted (e.g. by `retain_cached`).
+    /// Never touches the underlying iterator.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub fn is_evicted(&self, index: usize) -> bool {
+        matches!(self.vec.get(index), Some(None))
+    }
+
     /// If not already cached, repeatedly call `next` until we either reach `index` or `next` returns `None`.
+    /// Returns `None` for evicted elements as well, but evicted elements are never recomputed.
+    /// Once the source has ended, out-of-bounds indices return `None` without calling `next` again.
     /// Immutably borrow this entire `Cache` for the duration of your returned reference.
+    /// # Panics
+    /// With the `address-audit` feature, if any cached element has moved since we last looked.
     #[inline]
     pub fn get(&mut self, index: usize) -> Option<&I::Item> {
+        match self.get_with(index, |iter| {
+            Ok::<_, core::convert::Infallible>(iter.next())
+        }) {
+            Ok(cached) => cached,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Like `get`, but for every index in `range`: compute up to `range.end` (stopping early if the source ends),
+    /// then yield every element in `range`, skipping evicted elements.
+    /// # Panics
+    /// With the `address-audit` feature, if any cached element has moved since we last looked.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn get_range(
+        &mut self,
+        range: core::ops::Range<usize>,
+    ) -> impl Iterator<Item = Indexed<'_, I::Item>> {
+        if let Some(last) = range.end.checked_sub(1) {
+            let _ = self.get(last);
+        }
+        self.cached_range(range)
+    }
+
+    /// Like `get`, but pull from the source with `pull` instead of `Iterator::next`, bailing out as soon as `pull` fails.
+    /// Everything pulled before the failure stays cached.
+    /// # Errors
+    /// Whatever `pull` returns.
+    /// # Panics
+    /// With the `address-audit` feature, if any cached element has moved since we last looked.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn try_get_with<Error, Pull: FnMut(&mut I) -> Result<Option<I::Item>, Error>>(
+        &mut self,
+        index: usize,
+        pull: Pull,
+    ) -> Result<Option<&I::Item>, Error> {
+        self.get_with(index, pull)
+    }
+
+    /// See `try_get_with`.
+    #[inline]
+    fn get_with<Error, Pull: FnMut(&mut I) -> Result<Option<I::Item>, Error>>(
+        &mut self,
+        index: usize,
+        mut pull: Pull,
+    ) -> Result<Option<&I::Item>, Error> {
+        #[cfg(feature = "address-audit")]
+        self.audit.verify(&self.vec, &self.seeded);
+        #[cfg(feature = "unstable")]
+        {
+            self.demand = index.saturating_add(1).saturating_sub(self.vec.len());
+        }
+        #[cfg(feature = "tracing")]
+        let _span = (index >= self.vec.len() && self.known_len.is_none()).then(|| {
+            tracing::trace_span!("populate", from = self.vec.len(), until = index).entered()
+        });
         loop {
-            if let cached @ Some(_) = {
-                let v: *const _ = &self.vec;
+            // Both lookups below are the borrow checker's "conditional return" limitation (NLL problem case #3):
+            // the borrow is either returned right away or dead before `step` mutates anything,
+            // but it's inferred to last for the whole function either way.
+            if let Some(slot) = {
+                let v: *const Vec<_> = &self.vec;
+                // SAFETY: `v` points to `self.vec`, which we hold `&mut self` for, so it's valid and nothing else aliases it.
+                // If we return `slot`, it borrows `self` for as long as the returned reference, so nothing can push or evict underneath it.
+                // If not, `slot` is gone before `step` touches `self.vec`.
                 #[allow(unsafe_code)]
                 unsafe { &*v }.get(index)
             } {
-                return cached;
+                return Ok(slot.as_deref());
+            }
+            #[cfg(feature = "unstable")]
+            if let Some(seeded) = {
+                let m: *const BTreeMap<_, _> = &self.seeded;
+                // SAFETY: Same as above: either the reference is returned (borrowing `self`, so `seeded` can't be moved out of or rebuilt),
+                // or it's dead before `step` moves anything out of `self.seeded`.
+                #[allow(unsafe_code)]
+                unsafe { &*m }.get(&index)
+            } {
+                return Ok(Some(seeded));
+            }
+            if !self.step(&mut pull)? {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Drop every cached element for which `predicate` returns `false`, leaving a tombstone in its place.
+    /// Indices of all other elements (cached or not) are unchanged, and evicted elements are never recomputed.
+    /// Never touches the underlying iterator.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn retain_cached<Predicate: FnMut(&I::Item) -> bool>(&mut self, mut predicate: Predicate) {
+        #[cfg(feature = "tracing")]
+        let mut evicted: Option<core::ops::RangeInclusive<usize>> = None;
+        #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+        for (index, slot) in self.vec.iter_mut().enumerate() {
+            if slot.as_deref().is_some_and(|item| !predicate(item)) {
+                *slot = None;
+                self.retained = self.retained.wrapping_sub(1);
+                #[cfg(feature = "tracing")]
+                {
+                    evicted = Some(evicted.map_or(index, |range| *range.start())..=index);
+                }
+            }
+        }
+        #[cfg(feature = "tracing")]
+        if let Some(range) = evicted {
+            tracing::debug!(first = range.start(), last = range.end(), "evicted");
+        }
+    }
+
+    /// Drop the element at `index` (if cached), leaving a tombstone in its place. See `retain_cached`.
+    #[cfg(all(any(feature = "profiling", feature = "rand"), feature = "unstable"))]
+    #[inline]
+    pub(crate) fn evict(&mut self, index: usize) {
+        if let Some(slot) = self.vec.get_mut(index) {
+            if slot.take().is_some() {
+                self.retained = self.retained.wrapping_sub(1);
+            }
+        }
+    }
+
+    /// Cache `item` as the next element, ahead of anything the source has yet to produce (e.g. a value computed elsewhere and received over the network).
+    /// Everything not yet computed (including seeded elements) moves back by one index. If the source had already ended, `item` becomes the new last element.
+    /// # Errors
+    /// Hands `item` back if `Overflow::Refuse` won't let us cache it.
+    /// # Panics
+    /// If the cache is full and the policy is `Overflow::Panic`.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn push_front_of_source(&mut self, item: I::Item) -> Result<(), I::Item> {
+        if !self.make_room(self.vec.len()) {
+            return Err(item);
+        }
+        if !self.seeded.is_empty() {
+            #[cfg(feature = "address-audit")]
+            {
+                self.audit.verify(&self.vec, &self.seeded);
+                self.audit.shift_seeded();
+            }
+            self.seeded = core::mem::take(&mut self.seeded)
+                .into_iter()
+                .map(|(index, boxed)| (index.saturating_add(1), boxed))
+                .collect();
+        }
+        if let Some((ref mut start, ref mut end)) = self.back {
+            *start = start.saturating_add(1);
+            *end = end.saturating_add(1);
+        }
+        self.push(Box::new(item));
+        if let Some(ref mut len) = self.known_len {
+            *len = self.vec.len();
+        }
+        Ok(())
+    }
+
+    /// Keep everything cached, but pull anything new from `into_iter` instead, returning the old source.
+    /// Indices continue where the cache leaves off. Since the new source may have more, we forget having seen the end.
+    /// Anything pulled from the back of the old source is treated as seeded: the new source is expected to produce it too.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn replace_source<II: IntoIterator<IntoIter = I>>(&mut self, into_iter: II) -> I {
+        self.known_len = None;
+        // Seeded elements don't count toward `retained` until they're cached.
+        self.retained = self.retained.wrapping_sub(self.back_pending());
+        self.back = None;
+        self.poisoned = false;
+        core::mem::replace(&mut self.iter, into_iter.into_iter())
+    }
+
+    /// Keep everything cached (and every setting), but swap the source for `f(source)`, e.g. to erase its type.
+    /// `f` must produce the same elements the old source would have.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub(crate) fn map_source<J: Iterator<Item = I::Item>, F: FnOnce(I) -> J>(
+        self,
+        f: F,
+    ) -> Cache<J> {
+        Cache {
+            iter: f(self.iter),
+            vec: self.vec,
+            seeded: self.seeded,
+            back: self.back,
+            known_len: self.known_len,
+            poisoned: self.poisoned,
+            growth: self.growth,
+            cap: self.cap,
+            window: self.window,
+            retained: self.retained,
+            oldest: self.oldest,
+            pinned: self.pinned,
+            demand: self.demand,
+            truncations: self.truncations,
+            #[cfg(feature = "address-audit")]
+            audit: self.audit,
+        }
+    }
+
+    /// Drop everything cached and start over from `into_iter`, returning the old source.
+    /// Every `ElementId` issued so far stops resolving.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn reset_source<II: IntoIterator<IntoIter = I>>(&mut self, into_iter: II) -> I {
+        self.truncate(0, into_iter)
+    }
+
+    /// Drop every element from index `len` on (e.g. because the source turned out not to be referentially transparent past there)
+    /// and recompute them from `into_iter`, which must produce the element at `len` first. Returns the old source.
+    /// Every `ElementId` issued for a dropped element stops resolving; the rest are unaffected.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn truncate<II: IntoIterator<IntoIter = I>>(&mut self, len: usize, into_iter: II) -> I {
+        let len = len.min(self.vec.len());
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            from = len,
+            dropped = self.vec.len().wrapping_sub(len),
+            "invalidate"
+        );
+        let dropped = self.vec.get(len..).map_or(0, |dropped| {
+            dropped.iter().filter(|slot| slot.is_some()).count()
+        });
+        self.retained = self
+            .retained
+            .wrapping_sub(dropped)
+            .wrapping_sub(self.back_pending());
+        self.back = None;
+        self.oldest = self.oldest.min(len);
+        self.vec.truncate(len);
+        self.seeded.clear();
+        self.truncations.push(len);
+        #[cfg(feature = "address-audit")]
+        self.audit.verify(&self.vec, &self.seeded);
+        self.replace_source(into_iter)
+    }
+
+    /// Compute and cache every remaining element. Returns the total number of elements
+    /// (or, if the source has panicked, the number computed before it did). Never returns if the source is infinite.
+    /// # Errors
+    /// If `Overflow::Refuse` stopped us before the source ended.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn exhaust(&mut self) -> Result<usize, CacheFull> {
+        if let Some(len) = self.known_len {
+            return Ok(len);
+        }
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("exhaust", from = self.vec.len()).entered();
+        self.demand = self.iter.size_hint().0;
+        let mut pull = |iter: &mut I| Ok::<_, core::convert::Infallible>(iter.next());
+        while let Ok(true) = self.step(&mut pull) {}
+        match self.cap {
+            Some((max_cached, _)) if self.known_len.is_none() && !self.poisoned => Err(CacheFull {
+                max_cached,
+                index: self.vec.len(),
+            }),
+            Some(_) | None => Ok(self.vec.len()),
+        }
+    }
+
+    /// Index of the first evicted element, if any. Never touches the underlying iterator.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn first_evicted(&self) -> Option<usize> {
+        self.vec.iter().position(Option::is_none)
+    }
+
+    /// Move every cached element out (`None` for evicted elements) along with the source.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub(crate) fn into_items_and_source(self) -> (impl Iterator<Item = Option<I::Item>>, I) {
+        (
+            self.vec.into_iter().map(|slot| slot.map(|boxed| *boxed)),
+            self.iter,
+        )
+    }
+
+    /// Move every cached element out (`None` for evicted elements), dropping the source.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub(crate) fn into_items(self) -> impl Iterator<Item = Option<I::Item>> {
+        self.into_items_and_source().0
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl<I: DoubleEndedIterator + ExactSizeIterator> Cache<I> {
+    /// Total number of elements (including any evicted), computed or not: the source knows how many it has left.
+    #[inline]
+    #[must_use]
+    pub fn exact_len(&self) -> usize {
+        self.known_len.unwrap_or_else(|| {
+            self.back.map_or_else(
+                || self.vec.len().saturating_add(self.iter.len()),
+                |(_, end)| end,
+            )
+        })
+    }
+
+    /// Return the element `from_end` places before the last, pulling from the back of the source (and caching) everything after it
+    /// instead of computing everything before it. Indices are shared with the front: the last element is always `exact_len() - 1`.
+    /// `None` if out of bounds or evicted.
+    /// # Panics
+    /// With the `address-audit` feature, if any cached element has moved since we last looked.
+    #[inline]
+    pub fn get_back(&mut self, from_end: usize) -> Option<&I::Item> {
+        #[cfg(feature = "address-audit")]
+        self.audit.verify(&self.vec, &self.seeded);
+        let end = self.exact_len();
+        let index = end.checked_sub(from_end)?.checked_sub(1)?;
+        if index < self.vec.len() {
+            return self.vec.get(index)?.as_deref();
+        }
+        if self.poisoned {
+            return None;
+        }
+        while !self.seeded.contains_key(&index) {
+            let start = self.back.map_or(end, |(start, _)| start).checked_sub(1)?;
+            if !self.make_room_back(start) {
+                return None;
             }
-            self.vec.push(self.iter.next()?);
+            let item = self.iter.next_back()?;
+            self.back = Some((start, end));
+            self.retained = self.retained.wrapping_add(1);
+            let _ = self.seeded.entry(start).or_insert_with(|| Box::new(item));
         }
+        self.seeded.get(&index).map(|boxed| &**boxed)
+    }
+}
+
+impl<I: Iterator + Clone> Clone for Cache<I>
+where
+    I::Item: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            vec: self.vec.clone(),
+            #[cfg(feature = "unstable")]
+            seeded: self.seeded.clone(),
+            #[cfg(feature = "unstable")]
+            back: self.back,
+            known_len: self.known_len,
+            #[cfg(feature = "unstable")]
+            poisoned: self.poisoned,
+            #[cfg(feature = "unstable")]
+            growth: self.growth,
+            #[cfg(feature = "unstable")]
+            cap: self.cap,
+            #[cfg(feature = "unstable")]
+            window: self.window,
+            #[cfg(feature = "unstable")]
+            retained: self.retained,
+            #[cfg(feature = "unstable")]
+            oldest: self.oldest,
+            #[cfg(feature = "unstable")]
+            pinned: usize::MAX,
+            #[cfg(feature = "unstable")]
+            demand: self.demand,
+            #[cfg(feature = "unstable")]
+            truncations: self.truncations.clone(),
+            #[cfg(feature = "address-audit")]
+            audit: self.audit.clone(),
+        }
+    }
+
+    /// Reuses our storage, and the box of every element we both have, instead of allocating afresh.
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.iter.clone_from(&source.iter);
+        self.vec.clone_from(&source.vec);
+        self.known_len = source.known_len;
+        #[cfg(feature = "unstable")]
+        {
+            self.seeded.clone_from(&source.seeded);
+            self.back = source.back;
+            self.poisoned = source.poisoned;
+            self.growth = source.growth;
+            self.cap = source.cap;
+            self.window = source.window;
+            self.retained = source.retained;
+            self.oldest = source.oldest;
+            self.demand = source.demand;
+            self.truncations.clone_from(&source.truncations);
+        }
+        #[cfg(feature = "address-audit")]
+        {
+            self.audit = source.audit.clone();
+        }
+    }
+}
+
+/// Cache each item in turn ahead of anything the source has yet to produce. See `Cache::push_front_of_source`.
+/// Panics wherever `push_front_of_source` would hand an item back (i.e. under `Overflow::Refuse`), since there's nowhere to hand it.
+#[cfg(feature = "unstable")]
+impl<I: Iterator> Extend<I::Item> for Cache<I> {
+    #[inline]
+    fn extend<Items: IntoIterator<Item = I::Item>>(&mut self, items: Items) {
+        for item in items {
+            if self.push_front_of_source(item).is_err() {
+                panic!(
+                    "Cache full: couldn't cache element #{} without putting more than {} elements in the cache",
+                    self.vec.len(),
+                    self.cap.map_or(0, |(max_cached, _)| max_cached),
+                );
+            }
+        }
+    }
+}
+
+/// An element was requested that had been evicted.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Evicted {
+    /// Index of the evicted element.
+    pub index: usize,
+}
+
+#[cfg(feature = "unstable")]
+impl core::fmt::Display for Evicted {
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "element #{} was evicted from the cache", self.index)
     }
 }
 
@@ -0,0 +1,78 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Survive sources that panic (e.g. plugin-provided iterators you don't control).
+//! A caught panic leaves everything computed before it cached and seekable; the source itself is never touched again.
+
+use crate::Reiterator;
+use std::{
+    any::Any,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+/// The source panicked while computing an element.
+#[derive(Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct SourcePanicked {
+    /// Index of the element being computed.
+    pub index: usize,
+
+    /// Whatever the source panicked with.
+    pub payload: Box<dyn Any + Send>,
+}
+
+impl SourcePanicked {
+    /// The panic message, if the source panicked with a string (as `panic!` does).
+    #[inline]
+    #[must_use]
+    pub fn message(&self) -> Option<&str> {
+        self.payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| self.payload.downcast_ref::<String>().map(String::as_str))
+    }
+}
+
+impl core::fmt::Display for SourcePanicked {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "source panicked computing element #{}", self.index)?;
+        if let Some(message) = self.message() {
+            write!(f, ": {message}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SourcePanicked {}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Compute (if we haven't already) the first `n` elements, catching any panic from the source.
+    /// Returns the number of elements computed so far, which is less than `n` only if the source ended (or had already panicked).
+    /// # Errors
+    /// If the source panics. Everything computed before the panic stays cached, but the source is never touched again.
+    #[inline]
+    pub fn populate_catching(&mut self, n: usize) -> Result<usize, SourcePanicked> {
+        let Some(last) = n.checked_sub(1) else {
+            return Ok(self.cache.cached_len());
+        };
+        let outcome = self
+            .cache
+            .try_get_with(last, |iter| catch_unwind(AssertUnwindSafe(|| iter.next())));
+        let result = match outcome {
+            Ok(_) => Ok(self.cache.cached_len()),
+            Err(payload) => {
+                self.cache.poison();
+                Err(SourcePanicked {
+                    index: self.cache.cached_len(),
+                    payload,
+                })
+            }
+        };
+        self.notify();
+        result
+    }
+}
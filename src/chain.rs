@@ -0,0 +1,71 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Two `Reiterator`s back to back, sharing one index space.
+//! The second half's indices start wherever the first source ends, so the first source is exhausted only once something past it is requested.
+
+use crate::{indexed::Indexed, Reiterator};
+
+/// View of one `Reiterator` followed by another.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct Chain<A: Iterator, B: Iterator<Item = A::Item>> {
+    /// First half.
+    first: Reiterator<A>,
+
+    /// Second half, indexed from the end of the first.
+    second: Reiterator<B>,
+
+    /// Safe to edit! See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>> Chain<A, B> {
+    /// Return the element at the requested index *or compute it if we haven't*, provided it's in bounds.
+    /// Exhausts the first source if (and only if) `index` is past its end.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&A::Item> {
+        if self.first.cache.known_len().map_or(true, |len| index < len)
+            && (self.first.at(index).is_some() || self.first.cache.is_evicted(index))
+        {
+            return self.first.at(index);
+        }
+        // `at` just returned `None` without an eviction, so the first source has ended.
+        let offset = index.checked_sub(self.first.cache.known_len()?)?;
+        self.second.at(offset)
+    }
+
+    /// Whether `at(index)` is `None` only because the element was evicted (from either half), so `next` should skip it.
+    /// Never exhausts the first source: anything it hasn't computed can't have been evicted.
+    #[inline]
+    fn evicted(&mut self, index: usize) -> bool {
+        if self.first.cache.is_evicted(index) {
+            return true;
+        }
+        self.first
+            .cache
+            .known_len()
+            .and_then(|len| index.checked_sub(len))
+            .is_some_and(|offset| self.second.cache.is_evicted(offset))
+    }
+
+    crate::view::cursor!(A::Item);
+
+    crate::view::into_inner!(first: Reiterator<A>, second: Reiterator<B>);
+}
+
+impl<A: Iterator> Reiterator<A> {
+    /// View of this `Reiterator` followed by `other`, with indices continuing where this one ends.
+    #[inline(always)]
+    #[must_use]
+    pub const fn chain<B: Iterator<Item = A::Item>>(self, other: Reiterator<B>) -> Chain<A, B> {
+        Chain {
+            first: self,
+            second: other,
+            index: 0,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Turn a live channel into a rewindable, indexable history, and back again.
+//! Reading past the end blocks until a new message arrives; once every sender has been dropped, the stream is exhausted.
+
+use crate::{indexed::OwnedIndexed, Reiterator};
+use std::{sync::mpsc, thread};
+
+/// Create a `Reiterator` over every message ever received on an `mpsc` channel.
+/// The stream ends once every `Sender` has been dropped and every message received.
+#[inline(always)]
+#[must_use]
+pub fn reiterate_receiver<Item>(
+    receiver: mpsc::Receiver<Item>,
+) -> Reiterator<mpsc::IntoIter<Item>> {
+    Reiterator::new(receiver)
+}
+
+/// Create a `Reiterator` over every message ever received on a `crossbeam` channel.
+/// The stream ends once every `Sender` has been dropped and every message received.
+#[cfg(feature = "crossbeam")]
+#[inline(always)]
+#[must_use]
+pub fn reiterate_crossbeam_receiver<Item>(
+    receiver: crossbeam_channel::Receiver<Item>,
+) -> Reiterator<crossbeam_channel::IntoIter<Item>> {
+    Reiterator::new(receiver)
+}
+
+impl<I: Iterator + Send + 'static> Reiterator<I>
+where
+    I::Item: Clone + Send + 'static,
+{
+    /// Hand every element from the cursor on, with its index, to a bounded channel fed by a background thread,
+    /// for consumers that only speak `mpsc`. The thread stays at most `buffer` elements ahead of the receiver
+    /// (a `buffer` of zero hands each element over directly), and stops as soon as the source ends or the receiver is dropped.
+    /// If the source panics, the thread dies quietly and the receiver just sees the channel close.
+    #[inline]
+    #[must_use]
+    pub fn into_channel(mut self, buffer: usize) -> mpsc::Receiver<OwnedIndexed<I::Item>> {
+        let (sender, receiver) = mpsc::sync_channel(buffer);
+        drop(thread::spawn(move || {
+            while let Some(indexed) = self.next() {
+                if sender.send(OwnedIndexed::from(indexed)).is_err() {
+                    break;
+                }
+            }
+        }));
+        receiver
+    }
+}
@@ -0,0 +1,80 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Persist long computations as they go, and pick up where they left off.
+//! Hand each completed block to your own writer (serialize it however you like), then resume with `with_warm_cache`.
+
+use crate::{
+    cache::{Cache, Evicted},
+    subscribe::{Callback, Observer},
+    Reiterator,
+};
+use ::alloc::vec::Vec;
+
+impl<I: Iterator> Reiterator<I> {
+    /// Start with `prefix` already cached and `into_iter` producing everything after it,
+    /// e.g. every element saved by `auto_checkpoint` and a source skipping that many.
+    #[inline(always)]
+    pub fn with_warm_cache<Prefix: IntoIterator<Item = I::Item>, II: IntoIterator<IntoIter = I>>(
+        prefix: Prefix,
+        into_iter: II,
+    ) -> Self {
+        Self::from_cache(Cache::with_warm_cache(prefix, into_iter))
+    }
+
+    /// Take this `Reiterator` apart into everything computed so far, the source (ready to produce the next element), and the cursor,
+    /// e.g. to hand a long computation to another subsystem. Reassemble with `from_parts`.
+    /// Settings (probe, stride, subscriptions, ...) and any seeded elements past the computed prefix are dropped.
+    /// # Errors
+    /// If any element has been evicted (a `Vec` couldn't keep indices intact), handing back the `Reiterator` untouched.
+    #[inline]
+    pub fn into_parts(self) -> Result<(Vec<I::Item>, I, usize), (Evicted, Self)> {
+        if let Some(index) = self.cache.first_evicted() {
+            return Err((Evicted { index }, self));
+        }
+        let index = self.index;
+        let (items, source) = self.cache.into_items_and_source();
+        Ok((items.flatten().collect(), source, index))
+    }
+
+    /// Put a `Reiterator` back together from `into_parts`: `cached` already computed, `into_iter` producing everything after it,
+    /// and the cursor at `index`.
+    #[inline(always)]
+    pub fn from_parts<II: IntoIterator<IntoIter = I>>(
+        cached: Vec<I::Item>,
+        into_iter: II,
+        index: usize,
+    ) -> Self {
+        let mut reiterator = Self::with_warm_cache(cached, into_iter);
+        reiterator.index = index;
+        reiterator
+    }
+
+    /// Start with every element in `segments` (each a range of indices and the values for them, in any order, with gaps)
+    /// already cached, and `into_iter` producing the _whole_ sequence from index zero to fill the gaps on demand.
+    /// Seeded elements are skipped in the source with `Iterator::nth`, so resuming is cheap if the source's `nth` is.
+    /// See `Cache::with_segments`.
+    #[inline(always)]
+    pub fn with_segments<
+        Values: IntoIterator<Item = I::Item>,
+        Segments: IntoIterator<Item = (core::ops::Range<usize>, Values)>,
+        II: IntoIterator<IntoIter = I>,
+    >(
+        segments: Segments,
+        into_iter: II,
+    ) -> Self {
+        Self::from_cache(Cache::with_segments(segments, into_iter))
+    }
+
+    /// Hand every block of `every_n` consecutive elements to `writer` as soon as it's computed, counting from the very first element
+    /// (so blocks already computed are written on the next computation). Evicted elements are skipped.
+    /// A crash loses at most the block in progress; resume with `with_warm_cache`.
+    #[inline]
+    pub fn auto_checkpoint<Writer: Observer<I::Item>>(&mut self, every_n: usize, writer: Writer) {
+        self.subscribe_from(0, every_n, Callback::new(writer));
+        self.notify();
+    }
+}
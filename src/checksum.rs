@@ -0,0 +1,153 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Checksums over frozen or persisted sequences, so stale or corrupted warm-start data is rejected instead of silently producing wrong elements.
+//! Any `Hasher + Default` works; `Fnv1a` is always available, `Crc32` and `XxHash64` behind the `crc32` and `xxhash` features.
+//! Store `checksum` next to whatever you persist, then hand it back to `Frozen::verified` or `Reiterator::with_verified_warm_cache` on load.
+
+use crate::{frozen::Frozen, Reiterator};
+use ::alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+/// CRC-32 (IEEE), hardware-accelerated where available.
+#[cfg(feature = "crc32")]
+pub type Crc32 = crc32fast::Hasher;
+
+/// 64-bit xxHash.
+#[cfg(feature = "xxhash")]
+pub type XxHash64 = twox_hash::XxHash64;
+
+/// 64-bit FNV-1a: tiny and dependency-free, but slower and weaker than the others.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    #[inline(always)]
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// Checksum of a sequence (its length, then each element in order) under `H`.
+/// Only as stable across builds and platforms as `Item`'s `Hash` implementation (e.g. `usize` differs between 32- and 64-bit).
+#[inline]
+#[must_use]
+pub fn checksum<H: Hasher + Default, Item: Hash>(items: &[Item]) -> u64 {
+    let mut hasher = H::default();
+    items.len().hash(&mut hasher);
+    for item in items {
+        item.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Data didn't match the checksum stored with it.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct ChecksumMismatch {
+    /// Checksum stored alongside the data.
+    pub expected: u64,
+    /// Checksum of the data we actually got.
+    pub actual: u64,
+}
+
+impl core::fmt::Display for ChecksumMismatch {
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "checksum mismatch: expected {:#018x}, got {:#018x}",
+            self.expected, self.actual,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChecksumMismatch {}
+
+/// Accept `items` only if they hash to `expected`.
+#[inline]
+fn verify<H: Hasher + Default, Item: Hash>(
+    items: &[Item],
+    expected: u64,
+) -> Result<(), ChecksumMismatch> {
+    let actual = checksum::<H, _>(items);
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(ChecksumMismatch { expected, actual })
+    }
+}
+
+impl<Item: Hash> Frozen<Item> {
+    /// Checksum of every element under `H`, to store alongside wherever you persist this.
+    #[inline(always)]
+    #[must_use]
+    pub fn checksum<H: Hasher + Default>(&self) -> u64 {
+        checksum::<H, _>(self)
+    }
+
+    /// Rebuild a persisted `Frozen` buffer, provided it still matches the checksum stored with it.
+    /// # Errors
+    /// If the elements don't hash to `expected` under `H`.
+    #[inline]
+    pub fn verified<H: Hasher + Default>(
+        items: Vec<Item>,
+        expected: u64,
+    ) -> Result<Self, ChecksumMismatch> {
+        verify::<H, _>(&items, expected)?;
+        Ok(items.into())
+    }
+}
+
+impl<I: Iterator> Reiterator<I>
+where
+    I::Item: Hash,
+{
+    /// Like `with_warm_cache`, but only if the saved prefix still matches the checksum stored with it.
+    /// # Errors
+    /// If the prefix doesn't hash to `expected` under `H`.
+    #[inline]
+    pub fn with_verified_warm_cache<H: Hasher + Default, II: IntoIterator<IntoIter = I>>(
+        prefix: Vec<I::Item>,
+        expected: u64,
+        into_iter: II,
+    ) -> Result<Self, ChecksumMismatch> {
+        verify::<H, _>(&prefix, expected)?;
+        Ok(Self::with_warm_cache(prefix, into_iter))
+    }
+
+    /// Checksum of every element computed so far under `H`, e.g. to store alongside a checkpoint.
+    /// Same as `checksum` over those elements.
+    /// # Errors
+    /// If any element has been evicted, since we can't hash what we no longer have.
+    #[inline]
+    pub fn checksum<H: Hasher + Default>(&self) -> Result<u64, crate::cache::Evicted> {
+        let mut hasher = H::default();
+        self.cached_len().hash(&mut hasher);
+        for index in 0..self.cached_len() {
+            self.cache
+                .cached(index)
+                .ok_or(crate::cache::Evicted { index })?
+                .hash(&mut hasher);
+        }
+        Ok(hasher.finish())
+    }
+}
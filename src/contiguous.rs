@@ -0,0 +1,129 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Reiterator that stores elements contiguously, so it can hand out slices (e.g. overlapping windows).
+//! `cache::Cache` boxes each element so addresses never move (which is what lets `Reiterator` lend elements out past a push);
+//! this backend gives that up, so nothing it returns outlives the next computation, and elements can't be evicted.
+
+use crate::{frozen::Frozen, indexed::Indexed};
+use ::alloc::{vec, vec::Vec};
+
+/// Caching repeatable iterator over contiguous storage.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct ContiguousReiterator<I: Iterator> {
+    /// Iterator producing the input being cached.
+    iter: I,
+
+    /// Elements we've already pulled out of `iter`.
+    vec: Vec<I::Item>,
+
+    /// Whether `iter` has ended.
+    exhausted: bool,
+
+    /// Safe to edit! See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<I: Iterator> ContiguousReiterator<I> {
+    /// Set up the iterator to return the first element, but don't calculate it yet.
+    #[inline(always)]
+    pub fn new<II: IntoIterator<IntoIter = I>>(into_iter: II) -> Self {
+        Self {
+            iter: into_iter.into_iter(),
+            vec: vec![],
+            exhausted: false,
+            index: 0,
+        }
+    }
+
+    /// Compute (if we haven't already) every element before `end`, stopping early if the source ends.
+    #[inline]
+    fn force_until(&mut self, end: usize) {
+        while !self.exhausted && self.vec.len() < end {
+            match self.iter.next() {
+                Some(item) => self.vec.push(item),
+                None => self.exhausted = true,
+            }
+        }
+    }
+
+    /// Return the element at the requested index *or compute it if we haven't*, provided it's in bounds.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&I::Item> {
+        self.force_until(index.checked_add(1)?);
+        self.vec.get(index)
+    }
+
+    /// Return the current element, provided it's in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&mut self) -> Option<Indexed<'_, I::Item>> {
+        let index = self.index;
+        Some(Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+
+    /// Like `Iterator::next` but with a dependent lifetime.
+    #[inline]
+    pub fn next(&mut self) -> Option<Indexed<'_, I::Item>> {
+        let index = self.index;
+        self.index = index.checked_add(1)?;
+        Some(Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+
+    /// Set the index to zero.
+    #[inline(always)]
+    pub fn restart(&mut self) {
+        self.index = 0;
+    }
+
+    /// The `len` elements starting at `start`, computing them if we haven't. `None` unless all of them exist.
+    #[inline]
+    #[must_use]
+    pub fn window(&mut self, start: usize, len: usize) -> Option<&[I::Item]> {
+        let end = start.checked_add(len)?;
+        self.force_until(end);
+        self.vec.get(start..end)
+    }
+
+    /// Every element computed so far, as one slice. Never computes anything.
+    #[inline(always)]
+    #[must_use]
+    pub fn cached(&self) -> &[I::Item] {
+        &self.vec
+    }
+
+    /// Exhaust the source and hand every element over as a `Frozen` buffer, without copying.
+    /// Never returns if the source is infinite.
+    #[inline]
+    #[must_use]
+    pub fn freeze(mut self) -> Frozen<I::Item> {
+        self.force_until(usize::MAX);
+        self.vec.into()
+    }
+}
+
+impl<Item> Frozen<Item> {
+    /// The `len` elements starting at `start`, provided all of them are in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn window(&self, start: usize, len: usize) -> Option<&[Item]> {
+        self.get(start..start.checked_add(len)?)
+    }
+}
+
+/// Create a `ContiguousReiterator` from anything that can be turned into an `Iterator`.
+#[inline(always)]
+#[must_use]
+pub fn reiterate_contiguous<I: IntoIterator>(iter: I) -> ContiguousReiterator<I::IntoIter> {
+    ContiguousReiterator::new(iter)
+}
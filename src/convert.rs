@@ -0,0 +1,90 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversions out of a `Reiterator`, forcing every element, with an optional cap on how many we're willing to compute.
+
+use crate::Reiterator;
+use ::alloc::vec::Vec;
+
+impl<I: Iterator> From<Reiterator<I>> for Vec<I::Item> {
+    /// Exhaust the source and move every element out, skipping evicted elements.
+    /// Never returns if the source is infinite; see `Reiterator::with_budget` for a fallible alternative.
+    /// # Panics
+    /// If `Overflow::Refuse` stops us before the source ends, rather than silently returning only part of it.
+    #[inline]
+    fn from(mut value: Reiterator<I>) -> Self {
+        if let Err(full) = value.exhaust() {
+            panic!("Cache full: {full}");
+        }
+        value.cache.into_items().flatten().collect()
+    }
+}
+
+/// `Reiterator` paired with a maximum number of elements to compute when converting into a `Vec`.
+#[allow(missing_debug_implementations)]
+pub struct WithBudget<I: Iterator> {
+    /// Source, possibly partially cached already.
+    iter: Reiterator<I>,
+
+    /// Maximum total number of elements (including any evicted).
+    budget: usize,
+}
+
+/// Converting a `Reiterator` would have computed more elements than its budget allowed.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct OverBudget<Item> {
+    /// Every element within the budget, skipping evicted elements.
+    pub prefix: Vec<Item>,
+
+    /// The budget we ran into.
+    pub budget: usize,
+}
+
+impl<Item> core::fmt::Display for OverBudget<Item> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "source has more than {} elements", self.budget)
+    }
+}
+
+impl<I: Iterator> TryFrom<WithBudget<I>> for Vec<I::Item> {
+    type Error = OverBudget<I::Item>;
+
+    /// Compute at most `budget + 1` elements (just enough to tell whether there are more) and move them out.
+    #[inline]
+    fn try_from(value: WithBudget<I>) -> Result<Self, Self::Error> {
+        let WithBudget { mut iter, budget } = value;
+        let fits = iter.at(budget).is_none() && !iter.cache.is_evicted(budget);
+        let mut items = iter.cache.into_items();
+        let prefix = items.by_ref().take(budget).flatten().collect();
+        if fits {
+            Ok(prefix)
+        } else {
+            Err(OverBudget { prefix, budget })
+        }
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Exhaust the source and move every element out of the cache, without cloning. Evicted elements are skipped.
+    /// Same as `Vec::from`. Never returns if the source is infinite.
+    /// # Panics
+    /// If `Overflow::Refuse` stops us before the source ends.
+    #[inline(always)]
+    #[must_use]
+    pub fn into_vec(self) -> Vec<I::Item> {
+        self.into()
+    }
+
+    /// Cap how many elements (including any already cached) a conversion into a `Vec` may compute,
+    /// e.g. `Vec::try_from(iter.with_budget(1024))?`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn with_budget(self, budget: usize) -> WithBudget<I> {
+        WithBudget { iter: self, budget }
+    }
+}
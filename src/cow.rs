@@ -0,0 +1,151 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Reiterator over borrowed-or-owned items (`Iterator<Item = Cow<'_, T>>`) with uniform `&T` access.
+//! Borrowed items are cached as the references they already are; only owned items are boxed,
+//! so e.g. a lexer that mostly borrows from its input allocates only for the tokens it had to build.
+
+use crate::indexed::Indexed;
+use ::alloc::{
+    borrow::{Borrow, Cow, ToOwned},
+    boxed::Box,
+    vec::Vec,
+};
+
+/// One cached item, stored according to where it came from.
+enum Slot<'item, Item: ?Sized + ToOwned + 'item> {
+    /// Borrowed from the input, which already outlives us.
+    Borrowed(&'item Item),
+    /// Built by the source, so boxed (like any other cached element) to keep it in place.
+    Owned(Box<Item::Owned>),
+}
+
+impl<'item, Item: ?Sized + ToOwned + 'item> Slot<'item, Item> {
+    /// Store a `Cow` without copying what it borrows.
+    #[inline(always)]
+    fn new(cow: Cow<'item, Item>) -> Self {
+        match cow {
+            Cow::Borrowed(borrowed) => Self::Borrowed(borrowed),
+            Cow::Owned(owned) => Self::Owned(Box::new(owned)),
+        }
+    }
+
+    /// The item, whichever way it's stored.
+    #[inline(always)]
+    fn get(&self) -> &Item {
+        match *self {
+            Self::Borrowed(borrowed) => borrowed,
+            Self::Owned(ref owned) => (**owned).borrow(),
+        }
+    }
+}
+
+/// Caching repeatable iterator over `Cow`s that hands out `&T` whether the item was borrowed or owned.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct CowReiterator<
+    'item,
+    Item: ?Sized + ToOwned + 'item,
+    I: Iterator<Item = Cow<'item, Item>>,
+> {
+    /// Source of everything we haven't cached yet.
+    iter: I,
+
+    /// Every item computed so far, in order.
+    slots: Vec<Slot<'item, Item>>,
+
+    /// Whether `iter` has ended.
+    exhausted: bool,
+
+    /// Safe to edit! See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<'item, Item: ?Sized + ToOwned + 'item, I: Iterator<Item = Cow<'item, Item>>>
+    CowReiterator<'item, Item, I>
+{
+    /// Set up the iterator to return the first element, but don't calculate it yet.
+    #[inline(always)]
+    pub fn new<II: IntoIterator<IntoIter = I>>(into_iter: II) -> Self {
+        Self {
+            iter: into_iter.into_iter(),
+            slots: Vec::new(),
+            exhausted: false,
+            index: 0,
+        }
+    }
+
+    /// Return the element at the requested index *or compute it if we haven't*, provided it's in bounds.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&Item> {
+        while !self.exhausted && self.slots.len() <= index {
+            match self.iter.next() {
+                Some(cow) => self.slots.push(Slot::new(cow)),
+                None => self.exhausted = true,
+            }
+        }
+        self.slots.get(index).map(Slot::get)
+    }
+
+    /// Return the current element or compute it if we haven't, provided it's in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&mut self) -> Option<Indexed<'_, Item>> {
+        let index = self.index;
+        Some(Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+
+    /// Like `Iterator::next` but with a dependent lifetime.
+    #[inline]
+    pub fn next(&mut self) -> Option<Indexed<'_, Item>> {
+        let index = self.index;
+        self.index = index.checked_add(1)?;
+        Some(Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+
+    /// Set the index to zero.
+    #[inline(always)]
+    pub fn restart(&mut self) {
+        self.index = 0;
+    }
+
+    /// Number of elements computed so far.
+    #[inline(always)]
+    #[must_use]
+    pub fn cached_len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// If the element at `index` is cached and was borrowed, return it for as long as the input lives, not just as long as we do.
+    /// Never touches the source.
+    #[inline]
+    #[must_use]
+    pub fn borrowed(&self, index: usize) -> Option<&'item Item> {
+        match *self.slots.get(index)? {
+            Slot::Borrowed(borrowed) => Some(borrowed),
+            Slot::Owned(_) => None,
+        }
+    }
+}
+
+/// Create a `CowReiterator` from anything that can be turned into an `Iterator` over `Cow`s.
+#[inline(always)]
+#[must_use]
+pub fn reiterate_cows<
+    'item,
+    Item: ?Sized + ToOwned + 'item,
+    I: IntoIterator<Item = Cow<'item, Item>>,
+>(
+    iter: I,
+) -> CowReiterator<'item, Item, I::IntoIter> {
+    CowReiterator::new(iter)
+}
@@ -0,0 +1,100 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Debugging aid: a bounded record of how the cursor moved, for when a parser ends up in the weeds.
+//! Writes to the public `index` field can't be intercepted, so they show up as `Assigned` the next time the cursor is read.
+
+use crate::{CursorUse, Reiterator};
+use ::alloc::collections::VecDeque;
+
+/// One thing that happened to the cursor.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum CursorEvent {
+    /// The `index` field was set to this value directly (or via `restart`).
+    Assigned(usize),
+    /// `get`/`peek` read this index.
+    Get(usize),
+    /// `peek_ahead` read this index.
+    PeekAhead(usize),
+    /// `next` started reading from this index.
+    Next(usize),
+    /// `prev` started stepping back from this index.
+    Prev(usize),
+}
+
+/// Ring of the most recent cursor events.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) struct CursorHistory {
+    /// Oldest first.
+    events: VecDeque<CursorEvent>,
+
+    /// Maximum number of events kept; zero means we're not recording.
+    capacity: usize,
+
+    /// Where the cursor was when we last saw it move.
+    expected: usize,
+}
+
+impl CursorHistory {
+    /// Note that the cursor is at `index`, recording an `Assigned` event if it got there behind our back, then record `event`.
+    #[inline]
+    pub(crate) fn record(&mut self, index: usize, event: CursorEvent) {
+        if self.capacity == 0 {
+            return;
+        }
+        if index != self.expected {
+            self.push(CursorEvent::Assigned(index));
+        }
+        self.push(event);
+        self.expected = index;
+    }
+
+    /// Note that the cursor moved to `index` through a method, not an assignment.
+    #[inline(always)]
+    pub(crate) fn moved(&mut self, index: usize) {
+        self.expected = index;
+    }
+
+    /// Note what a method just did with the cursor, now at `index`.
+    #[inline]
+    pub(crate) fn track(&mut self, index: usize, used: CursorUse) {
+        match used {
+            CursorUse::Moved => self.moved(index),
+            CursorUse::Get => self.record(index, CursorEvent::Get(index)),
+            CursorUse::PeekAhead => self.record(index, CursorEvent::PeekAhead(index)),
+            CursorUse::Next => self.record(index, CursorEvent::Next(index)),
+            CursorUse::Prev => self.record(index, CursorEvent::Prev(index)),
+        }
+    }
+
+    /// Append an event, dropping the oldest if we're full.
+    #[inline]
+    fn push(&mut self, event: CursorEvent) {
+        if self.events.len() >= self.capacity {
+            let _ = self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Start recording the last `capacity` cursor events (or stop, with zero), discarding anything recorded so far.
+    #[inline]
+    pub fn record_cursor_history(&mut self, capacity: usize) {
+        self.cursor_history = CursorHistory {
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+            expected: self.index,
+        };
+    }
+
+    /// The most recent cursor events, oldest first.
+    #[inline(always)]
+    pub fn cursor_history(&self) -> impl Iterator<Item = CursorEvent> + '_ {
+        self.cursor_history.events.iter().copied()
+    }
+}
@@ -0,0 +1,72 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Loop over a fully computed cache forever, as a plain `Iterator` you can hand to anything.
+
+use crate::{
+    cache::{Cache, CacheFull},
+    indexed::Indexed,
+    Reiterator,
+};
+
+/// Endless `Iterator` over every cached element, wrapping back to the first after the last.
+/// Indices are the elements' own, so they wrap too. Evicted elements are skipped.
+#[allow(missing_debug_implementations)]
+pub struct Cycle<'cache, I: Iterator> {
+    /// Fully computed cache.
+    cache: &'cache Cache<I>,
+
+    /// Total number of elements (including any evicted).
+    len: usize,
+
+    /// Index of the next element to try.
+    index: usize,
+}
+
+impl<'cache, I: Iterator> Iterator for Cycle<'cache, I> {
+    type Item = Indexed<'cache, I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for _ in 0..self.len {
+            let index = self.index;
+            self.index = index
+                .checked_add(1)
+                .filter(|&incr| incr < self.len)
+                .unwrap_or(0);
+            if let Some(value) = self.cache.cached(index) {
+                return Some(Indexed { index, value });
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.cache.cached_range(0..self.len).next().is_some() {
+            (usize::MAX, None)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Exhaust the source (if we haven't already), then loop over every element forever, starting from the first.
+    /// Never returns if the source is infinite. The cursor is left alone.
+    /// # Errors
+    /// If `Overflow::Refuse` stopped us before the source ended.
+    #[inline]
+    pub fn cycle(&mut self) -> Result<Cycle<'_, I>, CacheFull> {
+        let len = self.cache.exhaust();
+        self.notify();
+        Ok(Cycle {
+            cache: &self.cache,
+            len: len?,
+            index: 0,
+        })
+    }
+}
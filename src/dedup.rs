@@ -0,0 +1,203 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Deduplicated views that remember which source index each kept element came from,
+//! so walking a noisy stream again skips duplicates without comparing anything twice.
+
+use crate::{indexed::Indexed, Reiterator};
+use ::alloc::{collections::BTreeSet, vec::Vec};
+
+/// View without consecutive duplicates (like `Vec::dedup_by`), with its own dense index space.
+/// Evicted elements are skipped, and an element following an evicted one it can't be compared to is kept.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct Dedup<I: Iterator, Same: FnMut(&I::Item, &I::Item) -> bool> {
+    /// Cache with duplicates.
+    iter: Reiterator<I>,
+
+    /// Whether an element duplicates the last one kept. Run at most once per source element.
+    same: Same,
+
+    /// Source index of every element kept so far, in order.
+    kept: Vec<usize>,
+
+    /// Number of source elements already compared.
+    tested: usize,
+
+    /// Safe to edit! Index in the _deduplicated_ index space. See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<I: Iterator, Same: FnMut(&I::Item, &I::Item) -> bool> Dedup<I, Same> {
+    /// Compare source elements until `index` of them have been kept or the source runs out.
+    #[inline]
+    fn populate(&mut self, index: usize) {
+        while self.kept.len() <= index {
+            if !self.iter.cache.is_evicted(self.tested) {
+                if self.iter.at(self.tested).is_none() {
+                    return;
+                }
+                let duplicate = match (
+                    self.kept
+                        .last()
+                        .and_then(|&last| self.iter.cache.cached(last)),
+                    self.iter.cache.cached(self.tested),
+                ) {
+                    (Some(last), Some(item)) => (self.same)(item, last),
+                    _ => false,
+                };
+                if !duplicate {
+                    self.kept.push(self.tested);
+                }
+            }
+            match self.tested.checked_add(1) {
+                Some(incr) => self.tested = incr,
+                None => return,
+            }
+        }
+    }
+
+    /// Index in the source of the element at `index` in the deduplicated view, provided it's in bounds.
+    #[inline]
+    #[must_use]
+    pub fn source_index(&mut self, index: usize) -> Option<usize> {
+        self.populate(index);
+        self.kept.get(index).copied()
+    }
+
+    /// Return the `index`th element kept, comparing more elements only if we haven't kept that many yet.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&I::Item> {
+        let source = self.source_index(index)?;
+        self.iter.at(source)
+    }
+
+    /// Whether `at(index)` is `None` only because the element kept there was evicted, so `next` should skip it.
+    #[inline]
+    fn evicted(&mut self, index: usize) -> bool {
+        self.source_index(index)
+            .is_some_and(|source| self.iter.cache.is_evicted(source))
+    }
+
+    crate::view::cursor!(I::Item);
+
+    crate::view::into_inner!(iter: Reiterator<I>);
+}
+
+/// View keeping only the first element with each key anywhere in the stream, with its own dense index space.
+/// Evicted elements are skipped.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct Unique<I: Iterator, KeyFn: FnMut(&I::Item) -> Key, Key: Ord> {
+    /// Cache with duplicates.
+    iter: Reiterator<I>,
+
+    /// Run at most once per source element.
+    key_fn: KeyFn,
+
+    /// Every key seen so far.
+    seen: BTreeSet<Key>,
+
+    /// Source index of every element kept so far, in order.
+    kept: Vec<usize>,
+
+    /// Number of source elements already keyed.
+    tested: usize,
+
+    /// Safe to edit! Index in the _deduplicated_ index space. See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<I: Iterator, KeyFn: FnMut(&I::Item) -> Key, Key: Ord> Unique<I, KeyFn, Key> {
+    /// Key source elements until `index` of them have been kept or the source runs out.
+    #[inline]
+    fn populate(&mut self, index: usize) {
+        while self.kept.len() <= index {
+            if !self.iter.cache.is_evicted(self.tested) {
+                match self.iter.at(self.tested) {
+                    Some(item) => {
+                        if self.seen.insert((self.key_fn)(item)) {
+                            self.kept.push(self.tested);
+                        }
+                    }
+                    None => return,
+                }
+            }
+            match self.tested.checked_add(1) {
+                Some(incr) => self.tested = incr,
+                None => return,
+            }
+        }
+    }
+
+    /// Index in the source of the element at `index` in the deduplicated view, provided it's in bounds.
+    #[inline]
+    #[must_use]
+    pub fn source_index(&mut self, index: usize) -> Option<usize> {
+        self.populate(index);
+        self.kept.get(index).copied()
+    }
+
+    /// Return the `index`th element kept, keying more elements only if we haven't kept that many yet.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&I::Item> {
+        let source = self.source_index(index)?;
+        self.iter.at(source)
+    }
+
+    /// Whether `at(index)` is `None` only because the element kept there was evicted, so `next` should skip it.
+    #[inline]
+    fn evicted(&mut self, index: usize) -> bool {
+        self.source_index(index)
+            .is_some_and(|source| self.iter.cache.is_evicted(source))
+    }
+
+    crate::view::cursor!(I::Item);
+
+    crate::view::into_inner!(iter: Reiterator<I>);
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// View without consecutive duplicates, remembering which elements it kept.
+    #[inline(always)]
+    #[must_use]
+    pub fn dedup(self) -> Dedup<I, impl FnMut(&I::Item, &I::Item) -> bool>
+    where
+        I::Item: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// View without consecutive elements for which `same(element, last_kept)` holds, remembering which elements it kept.
+    #[inline(always)]
+    #[must_use]
+    pub fn dedup_by<Same: FnMut(&I::Item, &I::Item) -> bool>(self, same: Same) -> Dedup<I, Same> {
+        Dedup {
+            iter: self,
+            same,
+            kept: Vec::new(),
+            tested: 0,
+            index: 0,
+        }
+    }
+
+    /// View keeping only the first element with each key, anywhere in the stream, remembering which elements it kept.
+    #[inline(always)]
+    #[must_use]
+    pub fn unique_by_key<KeyFn: FnMut(&I::Item) -> Key, Key: Ord>(
+        self,
+        key_fn: KeyFn,
+    ) -> Unique<I, KeyFn, Key> {
+        Unique {
+            iter: self,
+            key_fn,
+            seen: BTreeSet::new(),
+            kept: Vec::new(),
+            tested: 0,
+            index: 0,
+        }
+    }
+}
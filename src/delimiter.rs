@@ -0,0 +1,144 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Bracket matching over a stream of delimiter-like elements (e.g. tokens), built lazily as elements are forced.
+//! You say which elements open or close what; we remember every pair we find, so each element is classified at most once.
+
+use crate::Reiterator;
+use ::alloc::vec::Vec;
+
+/// What an element is, as far as bracket matching is concerned.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_enums)]
+pub enum Delimiter<Kind> {
+    /// Opens a group of this kind (e.g. `(`).
+    Open(Kind),
+    /// Closes a group of this kind (e.g. `)`).
+    Close(Kind),
+    /// Anything else.
+    Neither,
+}
+
+/// What we've learned about one element.
+#[derive(Clone, Copy, Debug)]
+enum Slot {
+    /// Not a delimiter (or evicted before we could look).
+    Neither,
+    /// Opening delimiter, with its partner once we've found it.
+    Open(Option<usize>),
+    /// Closing delimiter, with its partner if it had one.
+    Close(Option<usize>),
+}
+
+/// View that can find the partner of any delimiter, scanning only as far as it has to.
+#[allow(missing_debug_implementations)]
+pub struct Delimited<I: Iterator, Classify: FnMut(&I::Item) -> Delimiter<Kind>, Kind: PartialEq> {
+    /// Cached elements.
+    iter: Reiterator<I>,
+
+    /// Run at most once per element.
+    classify: Classify,
+
+    /// Everything we know about each element classified so far.
+    slots: Vec<Slot>,
+
+    /// Groups opened but not yet closed, innermost last.
+    stack: Vec<(usize, Kind)>,
+}
+
+impl<I: Iterator, Classify: FnMut(&I::Item) -> Delimiter<Kind>, Kind: PartialEq>
+    Delimited<I, Classify, Kind>
+{
+    /// Classify the next element (computing it if we haven't), pairing it up if it closes the innermost open group.
+    /// A closer of the wrong kind stays unmatched and leaves the open group open. Returns whether there was an element.
+    #[inline]
+    fn scan_one(&mut self) -> bool {
+        let index = self.slots.len();
+        let delimiter = if self.iter.cache.is_evicted(index) {
+            Delimiter::Neither
+        } else {
+            match self.iter.at(index) {
+                Some(item) => (self.classify)(item),
+                None => return false,
+            }
+        };
+        let slot = match delimiter {
+            Delimiter::Neither => Slot::Neither,
+            Delimiter::Open(kind) => {
+                self.stack.push((index, kind));
+                Slot::Open(None)
+            }
+            Delimiter::Close(kind) => match self.stack.last() {
+                Some(&(open, ref open_kind)) if *open_kind == kind => {
+                    drop(self.stack.pop());
+                    if let Some(partner) = self.slots.get_mut(open) {
+                        *partner = Slot::Open(Some(index));
+                    }
+                    Slot::Close(Some(open))
+                }
+                _ => Slot::Close(None),
+            },
+        };
+        self.slots.push(slot);
+        true
+    }
+
+    /// Index of the delimiter paired with the one at `index`, scanning forward only as far as it takes.
+    /// `None` if `index` isn't a delimiter, is unmatched, or is out of bounds.
+    #[inline]
+    pub fn matching(&mut self, index: usize) -> Option<usize> {
+        while self.slots.len() <= index {
+            if !self.scan_one() {
+                return None;
+            }
+        }
+        loop {
+            match *self.slots.get(index)? {
+                Slot::Neither | Slot::Close(None) => return None,
+                Slot::Open(Some(partner)) | Slot::Close(Some(partner)) => return Some(partner),
+                Slot::Open(None) => {
+                    if !self.scan_one() {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Return the element at the requested index *or compute it if we haven't*, provided it's in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&I::Item> {
+        self.iter.at(index)
+    }
+
+    /// Return the underlying `Reiterator`, with everything this view computed still cached (but the pairs dropped).
+    #[inline(always)]
+    #[must_use]
+    pub fn into_inner(self) -> Reiterator<I> {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// View that can find the partner of any delimiter, as decided by `classify`, sharing this cache.
+    #[inline(always)]
+    #[must_use]
+    pub const fn matching_delimiters<
+        Classify: FnMut(&I::Item) -> Delimiter<Kind>,
+        Kind: PartialEq,
+    >(
+        self,
+        classify: Classify,
+    ) -> Delimited<I, Classify, Kind> {
+        Delimited {
+            iter: self,
+            classify,
+            slots: Vec::new(),
+            stack: Vec::new(),
+        }
+    }
+}
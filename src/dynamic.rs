@@ -0,0 +1,110 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Type-erased `Reiterator`s, for when the concrete iterator type is unnameable or would leak into your API.
+//! `Send` is opt-in: pick `DynSendReiterator` only if you need to move it across threads.
+//! `Erased` goes one step further to cut down on monomorphization: every `Reiterator<Erased<T>>` shares one instantiation per `T`,
+//! at the cost of a virtual call each time the cache misses.
+
+use crate::Reiterator;
+use ::alloc::boxed::Box;
+
+/// `Reiterator` over any boxed iterator. Not `Send`, so any iterator will do.
+pub type DynReiterator<Item> = Reiterator<Box<dyn Iterator<Item = Item>>>;
+
+/// `Reiterator` over any boxed `Send` iterator. `Send` whenever `Item` is.
+pub type DynSendReiterator<Item> = Reiterator<Box<dyn Iterator<Item = Item> + Send>>;
+
+/// Create a `DynReiterator` from anything that can be turned into an `Iterator`.
+#[inline(always)]
+#[must_use]
+pub fn reiterate_dyn<I: IntoIterator>(iter: I) -> DynReiterator<I::Item>
+where
+    I::IntoIter: 'static,
+{
+    let boxed: Box<dyn Iterator<Item = I::Item>> = Box::new(iter.into_iter());
+    Reiterator::new(boxed)
+}
+
+/// Create a `DynSendReiterator` from anything that can be turned into a `Send` `Iterator`.
+#[inline(always)]
+#[must_use]
+pub fn reiterate_dyn_send<I: IntoIterator>(iter: I) -> DynSendReiterator<I::Item>
+where
+    I::IntoIter: Send + 'static,
+{
+    let boxed: Box<dyn Iterator<Item = I::Item> + Send> = Box::new(iter.into_iter());
+    Reiterator::new(boxed)
+}
+
+/// Source reduced to a single boxed `FnMut() -> Option<T>`, so only `T` shows up in the type.
+/// Gives up the original iterator's `size_hint`.
+pub struct Erased<T> {
+    /// Produces the next element, if any.
+    next: Box<dyn FnMut() -> Option<T>>,
+}
+
+impl<T> Erased<T> {
+    /// Erase the type of anything that can be turned into an `Iterator`.
+    #[inline(always)]
+    #[must_use]
+    pub fn new<I: IntoIterator<Item = T>>(iter: I) -> Self
+    where
+        I::IntoIter: 'static,
+    {
+        let mut iter = iter.into_iter();
+        Self {
+            next: Box::new(move || iter.next()),
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for Erased<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Erased")
+    }
+}
+
+impl<T> Iterator for Erased<T> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.next)()
+    }
+}
+
+impl<I: Iterator + 'static> Reiterator<I> {
+    /// Erase the source's type, keeping everything cached, the cursor, and every setting.
+    #[inline]
+    #[must_use]
+    pub fn erase(self) -> Reiterator<Erased<I::Item>> {
+        Reiterator {
+            cache: self.cache.map_source(Erased::new),
+            index: self.index,
+            back_index: self.back_index,
+            subscriptions: self.subscriptions,
+            probe: self.probe,
+            budgeted: self.budgeted,
+            stride: self.stride,
+            #[cfg(all(feature = "cursor-history", feature = "unstable"))]
+            cursor_history: self.cursor_history,
+            #[cfg(all(feature = "profiling", feature = "unstable"))]
+            access_profile: self.access_profile,
+        }
+    }
+}
+
+/// Create a `Reiterator<Erased<_>>` from anything that can be turned into an `Iterator`.
+#[inline(always)]
+#[must_use]
+pub fn reiterate_erased<I: IntoIterator>(iter: I) -> Reiterator<Erased<I::Item>>
+where
+    I::IntoIter: 'static,
+{
+    Reiterator::new(Erased::new(iter))
+}
@@ -0,0 +1,265 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Reiterator over a source that knows its own length up front (`ExactSizeIterator`).
+//! We allocate once up front, and never probe past the end to find out where it is.
+
+use crate::{
+    cache::{Cache, Probe},
+    indexed::Indexed,
+    IntoIter, Map, MapIndices, MapValues, Reiterator,
+};
+
+/// Caching repeatable iterator whose length is known before computing anything.
+/// Dereferences to the underlying `Reiterator`, so e.g. `.index` and `.restart()` work as usual.
+#[allow(missing_debug_implementations)]
+pub struct ExactReiterator<I: ExactSizeIterator> {
+    /// Underlying cache and cursor.
+    reiterator: Reiterator<I>,
+}
+
+impl<I: ExactSizeIterator> ExactReiterator<I> {
+    /// Set up the iterator to return the first element, allocating room for every element but computing none of them.
+    #[inline]
+    pub fn new<II: IntoIterator<IntoIter = I>>(into_iter: II) -> Self {
+        let iter = into_iter.into_iter();
+        let len = iter.len();
+        Self {
+            reiterator: Reiterator::from_cache(Cache::with_capacity(iter, len)),
+        }
+    }
+
+    /// Total number of elements, computed or not. Never touches the source.
+    /// Asked of the cache each time rather than remembered, since e.g. `push_front_of_source` or `replace_source` can change it.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        let cache = &self.reiterator.cache;
+        cache
+            .known_len()
+            .unwrap_or_else(|| cache.cached_len().saturating_add(cache.uncached_hint().0))
+    }
+
+    /// Whether the source has no elements at all. Never touches the source.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the element at the requested index *or compute it if we haven't*, provided it's in bounds.
+    /// Out-of-bounds indices are rejected immediately, without touching the source.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&I::Item> {
+        if index < self.len() {
+            self.reiterator.at(index)
+        } else {
+            None
+        }
+    }
+
+    /// Return the current element or compute it if we haven't, provided it's in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&mut self) -> Option<Indexed<'_, I::Item>> {
+        let index = self.reiterator.index;
+        Some(Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+
+    /// Return the underlying `Reiterator`. To keep an exact length through a `map`, use `map` & co. here instead.
+    #[inline(always)]
+    #[must_use]
+    pub fn into_inner(self) -> Reiterator<I> {
+        self.reiterator
+    }
+
+    /// The underlying `Reiterator`, with everything that could cut a read short lifted: see `Exact`.
+    #[inline]
+    fn unlimited(self) -> Reiterator<I> {
+        let mut reiterator = self.reiterator;
+        reiterator.probe = Probe::Exhaust;
+        reiterator.cache.lift_limits();
+        #[cfg(feature = "profiling")]
+        {
+            reiterator.access_profile.suspended = true;
+        }
+        reiterator
+    }
+
+    /// Map `Indexed`s to a known lifetime, keeping an exact length. See `Exact`.
+    #[inline]
+    #[must_use]
+    pub fn map<UnReferenceInator: FnMut(Indexed<'_, I::Item>) -> Output, Output>(
+        self,
+        un_reference_inator: UnReferenceInator,
+    ) -> Exact<Map<I, UnReferenceInator, Output>> {
+        Exact {
+            adapter: self.unlimited().map(un_reference_inator),
+        }
+    }
+
+    /// Map indices to a known lifetime, keeping an exact length. See `Exact`.
+    #[inline]
+    #[must_use]
+    pub fn map_indices<UnReferenceInator: FnMut(usize) -> Output, Output>(
+        self,
+        un_reference_inator: UnReferenceInator,
+    ) -> Exact<MapIndices<I, UnReferenceInator, Output>> {
+        Exact {
+            adapter: self.unlimited().map_indices(un_reference_inator),
+        }
+    }
+
+    /// Map values to a known lifetime, keeping an exact length. See `Exact`.
+    #[inline]
+    #[must_use]
+    pub fn map_values<UnReferenceInator: FnMut(&I::Item) -> Output, Output>(
+        self,
+        un_reference_inator: UnReferenceInator,
+    ) -> Exact<MapValues<I, UnReferenceInator, Output>> {
+        Exact {
+            adapter: self.unlimited().map_values(un_reference_inator),
+        }
+    }
+}
+
+impl<I: ExactSizeIterator> IntoIterator for ExactReiterator<I>
+where
+    I::Item: Clone,
+{
+    type Item = (usize, I::Item);
+    type IntoIter = Exact<IntoIter<I>>;
+
+    /// Clone values lazily as we produce them, keeping an exact length. See `Exact`.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        Exact {
+            adapter: self.unlimited().into_iter(),
+        }
+    }
+}
+
+/// Adapter over an `ExactReiterator` that always knows exactly how many elements it has left, so it's an `ExactSizeIterator`.
+/// A plain `Reiterator` can't promise that: a `Probe` budget, a cap, a window, or auto-tuning can end reads early.
+/// So all of those are lifted when this is made, and the `Reiterator` stays out of reach until `into_inner`.
+#[allow(missing_debug_implementations)]
+pub struct Exact<Adapter> {
+    /// Adapter over a `Reiterator` with nothing left to cut its reads short.
+    adapter: Adapter,
+}
+
+impl<Adapter> Exact<Adapter> {
+    /// Return the adapter itself, giving up the length guarantee.
+    #[inline(always)]
+    #[must_use]
+    pub fn into_inner(self) -> Adapter {
+        self.adapter
+    }
+}
+
+impl<Adapter: Iterator> Iterator for Exact<Adapter> {
+    type Item = Adapter::Item;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.adapter.next()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.adapter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator, UnReferenceInator: FnMut(Indexed<'_, I::Item>) -> Output, Output>
+    ExactSizeIterator for Exact<Map<I, UnReferenceInator, Output>>
+{
+}
+
+impl<I: ExactSizeIterator, UnReferenceInator: FnMut(usize) -> Output, Output> ExactSizeIterator
+    for Exact<MapIndices<I, UnReferenceInator, Output>>
+{
+}
+
+impl<I: ExactSizeIterator, UnReferenceInator: FnMut(&I::Item) -> Output, Output> ExactSizeIterator
+    for Exact<MapValues<I, UnReferenceInator, Output>>
+{
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Exact<IntoIter<I>> where I::Item: Clone {}
+
+// SAFETY (for each `TrustedLen` impl below): `size_hint` counts the non-evicted elements ahead of the cursor that are already cached,
+// plus exactly what the (`TrustedLen`) source has left, and `next` returns every one of them and nothing else:
+// no `Probe` budget, cap, window or auto-tuning is left to skip or refuse one (see `ExactReiterator::unlimited`),
+// nothing can evict while the `Reiterator` is out of reach, and once the source ends or panics, it counts as empty.
+#[cfg(feature = "nightly")]
+#[allow(unsafe_code)]
+unsafe impl<
+        I: ExactSizeIterator + core::iter::TrustedLen,
+        UnReferenceInator: FnMut(Indexed<'_, I::Item>) -> Output,
+        Output,
+    > core::iter::TrustedLen for Exact<Map<I, UnReferenceInator, Output>>
+{
+}
+
+#[cfg(feature = "nightly")]
+#[allow(unsafe_code)]
+unsafe impl<
+        I: ExactSizeIterator + core::iter::TrustedLen,
+        UnReferenceInator: FnMut(usize) -> Output,
+        Output,
+    > core::iter::TrustedLen for Exact<MapIndices<I, UnReferenceInator, Output>>
+{
+}
+
+#[cfg(feature = "nightly")]
+#[allow(unsafe_code)]
+unsafe impl<
+        I: ExactSizeIterator + core::iter::TrustedLen,
+        UnReferenceInator: FnMut(&I::Item) -> Output,
+        Output,
+    > core::iter::TrustedLen for Exact<MapValues<I, UnReferenceInator, Output>>
+{
+}
+
+#[cfg(feature = "nightly")]
+#[allow(unsafe_code)]
+unsafe impl<I: ExactSizeIterator + core::iter::TrustedLen> core::iter::TrustedLen
+    for Exact<IntoIter<I>>
+where
+    I::Item: Clone,
+{
+}
+
+impl<I: ExactSizeIterator> core::ops::Deref for ExactReiterator<I> {
+    type Target = Reiterator<I>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.reiterator
+    }
+}
+
+impl<I: ExactSizeIterator> core::ops::DerefMut for ExactReiterator<I> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.reiterator
+    }
+}
+
+/// Create an `ExactReiterator` from anything that can be turned into an `ExactSizeIterator`.
+#[inline(always)]
+#[must_use]
+pub fn reiterate_exact<I: IntoIterator>(iter: I) -> ExactReiterator<I::IntoIter>
+where
+    I::IntoIter: ExactSizeIterator,
+{
+    ExactReiterator::new(iter)
+}
@@ -0,0 +1,98 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Filtered view that remembers which elements passed, so the predicate runs at most once per element.
+
+use crate::{indexed::Indexed, Reiterator};
+use ::alloc::vec::Vec;
+
+/// Filtered view with its own dense index space: `at(0)` is the first element that passed, and so on.
+/// Evicted elements never pass.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct Filter<I: Iterator, Predicate: FnMut(&I::Item) -> bool> {
+    /// Unfiltered cache.
+    iter: Reiterator<I>,
+
+    /// Run at most once per source element.
+    predicate: Predicate,
+
+    /// Source index of every element that passed so far, in order.
+    passed: Vec<usize>,
+
+    /// Number of source elements already tested.
+    tested: usize,
+
+    /// Safe to edit! Index in the _filtered_ index space. See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<I: Iterator, Predicate: FnMut(&I::Item) -> bool> Filter<I, Predicate> {
+    /// Test source elements until `index` of them have passed or the source runs out.
+    #[inline]
+    fn populate(&mut self, index: usize) {
+        while self.passed.len() <= index {
+            if !self.iter.cache.is_evicted(self.tested) {
+                match self.iter.at(self.tested) {
+                    Some(item) => {
+                        if (self.predicate)(item) {
+                            self.passed.push(self.tested);
+                        }
+                    }
+                    None => return,
+                }
+            }
+            match self.tested.checked_add(1) {
+                Some(incr) => self.tested = incr,
+                None => return,
+            }
+        }
+    }
+
+    /// Index in the unfiltered source of the element at `index` in the filtered view, provided it's in bounds.
+    #[inline]
+    #[must_use]
+    pub fn source_index(&mut self, index: usize) -> Option<usize> {
+        self.populate(index);
+        self.passed.get(index).copied()
+    }
+
+    /// Return the `index`th element that passed, testing more elements only if we haven't found that many yet.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&I::Item> {
+        let source = self.source_index(index)?;
+        self.iter.at(source)
+    }
+
+    /// Whether `at(index)` is `None` only because the element that passed there was evicted, so `next` should skip it.
+    #[inline]
+    fn evicted(&mut self, index: usize) -> bool {
+        self.source_index(index)
+            .is_some_and(|source| self.iter.cache.is_evicted(source))
+    }
+
+    crate::view::cursor!(I::Item);
+
+    crate::view::into_inner!(iter: Reiterator<I>);
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Filtered view that caches which elements passed, so rewinding and re-iterating never re-runs `predicate`.
+    #[inline(always)]
+    #[must_use]
+    pub fn filter<Predicate: FnMut(&I::Item) -> bool>(
+        self,
+        predicate: Predicate,
+    ) -> Filter<I, Predicate> {
+        Filter {
+            iter: self,
+            predicate,
+            passed: Vec::new(),
+            tested: 0,
+            index: 0,
+        }
+    }
+}
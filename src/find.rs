@@ -0,0 +1,122 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Forward searches from the cursor, optionally remembering every match so repeated searches never re-run an expensive predicate.
+//! Searches walk the dense index space (ignoring any stride) and skip evicted elements.
+
+use crate::{indexed::Indexed, Reiterator};
+use ::alloc::vec::Vec;
+
+/// Predicate that remembers where it matched. Reuse one across searches to make repeats O(log n) with no predicate calls.
+/// The predicate runs at most once per element, on a contiguous prefix of the stream.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Finder<Predicate> {
+    /// What we're looking for.
+    predicate: Predicate,
+
+    /// Index of every match among the first `tested` elements, in order.
+    matches: Vec<usize>,
+
+    /// Number of leading elements already run through `predicate`.
+    tested: usize,
+}
+
+impl<Predicate> Finder<Predicate> {
+    /// Wrap a predicate, remembering nothing yet.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(predicate: Predicate) -> Self {
+        Self {
+            predicate,
+            matches: Vec::new(),
+            tested: 0,
+        }
+    }
+
+    /// Every match found so far, in order.
+    #[inline(always)]
+    #[must_use]
+    pub fn matches(&self) -> &[usize] {
+        &self.matches
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Like `Iterator::position`, but returns the absolute index of the first element at or after the cursor matching `predicate`,
+    /// leaving the cursor just past it (or at the end, if nothing matched). Everything scanned stays cached.
+    #[inline]
+    pub fn position<Predicate: FnMut(&I::Item) -> bool>(
+        &mut self,
+        mut predicate: Predicate,
+    ) -> Option<usize> {
+        loop {
+            let index = self.index;
+            if !self.cache.is_evicted(index) {
+                let matched = predicate(self.at(index)?);
+                self.index = index.checked_add(1)?;
+                if matched {
+                    return Some(index);
+                }
+            } else {
+                self.index = index.checked_add(1)?;
+            }
+        }
+    }
+
+    /// Like `Iterator::find`: the first element at or after the cursor matching `predicate`,
+    /// leaving the cursor just past it (or at the end, if nothing matched). Everything scanned stays cached.
+    #[inline]
+    pub fn find<Predicate: FnMut(&I::Item) -> bool>(
+        &mut self,
+        predicate: Predicate,
+    ) -> Option<Indexed<'_, I::Item>> {
+        let index = self.position(predicate)?;
+        self.at(index).map(|value| Indexed { index, value })
+    }
+
+    /// Like `position`, but remembering matches in `finder`, so searching the same region again never calls the predicate.
+    #[inline]
+    pub fn position_memo<Predicate: FnMut(&I::Item) -> bool>(
+        &mut self,
+        finder: &mut Finder<Predicate>,
+    ) -> Option<usize> {
+        let start = self.index;
+        let known = finder.matches.partition_point(|&matched| matched < start);
+        if let Some(&matched) = finder.matches.get(known) {
+            self.index = matched.checked_add(1)?;
+            return Some(matched);
+        }
+        loop {
+            let index = finder.tested;
+            if !self.cache.is_evicted(index) {
+                let Some(item) = self.at(index) else {
+                    self.index = start.max(index);
+                    return None;
+                };
+                if (finder.predicate)(item) {
+                    finder.matches.push(index);
+                    finder.tested = index.checked_add(1)?;
+                    if index >= start {
+                        self.index = finder.tested;
+                        return Some(index);
+                    }
+                    continue;
+                }
+            }
+            finder.tested = index.checked_add(1)?;
+        }
+    }
+
+    /// Like `find`, but remembering matches in `finder`, so searching the same region again never calls the predicate.
+    #[inline]
+    pub fn find_memo<Predicate: FnMut(&I::Item) -> bool>(
+        &mut self,
+        finder: &mut Finder<Predicate>,
+    ) -> Option<Indexed<'_, I::Item>> {
+        let index = self.position_memo(finder)?;
+        self.at(index).map(|value| Indexed { index, value })
+    }
+}
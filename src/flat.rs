@@ -0,0 +1,157 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Flattened view: each element expands into any number of inner elements, indexed as one flat sequence (e.g. lines into tokens).
+//! Both levels are cached, and we remember where each outer element starts, so seeking anywhere already flattened never re-flattens.
+
+use crate::{indexed::Indexed, Reiterator};
+use ::alloc::vec::Vec;
+
+/// View of every inner element of every outer element, in one flat index space.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct FlatMap<I: Iterator, Function: FnMut(&I::Item) -> Inner, Inner: IntoIterator> {
+    /// Cached outer elements.
+    iter: Reiterator<I>,
+
+    /// Run at most once per outer element.
+    function: Function,
+
+    /// Inner elements flattened so far.
+    flat: Vec<Inner::Item>,
+
+    /// Flat index at which each outer element expanded so far starts.
+    starts: Vec<usize>,
+
+    /// Rest of the outer element we're partway through flattening.
+    current: Option<Inner::IntoIter>,
+
+    /// Whether we've run out of outer elements.
+    done: bool,
+
+    /// Safe to edit! See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<I: Iterator, Function: FnMut(&I::Item) -> Inner, Inner: IntoIterator>
+    FlatMap<I, Function, Inner>
+{
+    /// Flatten one more inner element. Returns whether there was one.
+    /// An evicted outer element can't be expanded, so it contributes nothing.
+    #[inline]
+    fn step(&mut self) -> bool {
+        loop {
+            if let Some(item) = self.current.as_mut().and_then(Iterator::next) {
+                self.flat.push(item);
+                return true;
+            }
+            self.current = None;
+            if self.done {
+                return false;
+            }
+            let outer = self.starts.len();
+            if self.iter.cache.is_evicted(outer) {
+                self.starts.push(self.flat.len());
+                continue;
+            }
+            match self.iter.at(outer) {
+                Some(item) => {
+                    self.current = Some((self.function)(item).into_iter());
+                    self.starts.push(self.flat.len());
+                }
+                None => self.done = true,
+            }
+        }
+    }
+
+    /// Return the inner element at the requested flat index *or flatten up to it if we haven't*, provided it's in bounds.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&Inner::Item> {
+        while self.flat.len() <= index {
+            if !self.step() {
+                return None;
+            }
+        }
+        self.flat.get(index)
+    }
+
+    /// Return the current inner element, provided it's in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&mut self) -> Option<Indexed<'_, Inner::Item>> {
+        let index = self.index;
+        Some(Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+
+    /// Like `Iterator::next` but with a dependent lifetime.
+    #[inline]
+    pub fn next(&mut self) -> Option<Indexed<'_, Inner::Item>> {
+        let index = self.index;
+        self.index = index.checked_add(1)?;
+        Some(Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+
+    /// Set the index to zero. Never re-flattens anything.
+    #[inline(always)]
+    pub fn restart(&mut self) {
+        self.index = 0;
+    }
+
+    /// Which outer element the inner element at flat index `index` came from, and where it sits within it,
+    /// flattening up to it if we haven't.
+    #[inline]
+    pub fn locate(&mut self, index: usize) -> Option<(usize, usize)> {
+        if self.at(index).is_none() {
+            return None;
+        }
+        // Outer elements that expanded to nothing share a start with their successor; the last of them is the one we want.
+        let outer = self
+            .starts
+            .partition_point(|&start| start <= index)
+            .checked_sub(1)?;
+        Some((outer, index.wrapping_sub(*self.starts.get(outer)?)))
+    }
+
+    /// Flat index of the first inner element of outer element `outer` (even if it expanded to nothing), flattening up to it if we haven't.
+    #[inline]
+    pub fn start_of(&mut self, outer: usize) -> Option<usize> {
+        while self.starts.len() <= outer && self.step() {}
+        self.starts.get(outer).copied()
+    }
+
+    /// Return the outer `Reiterator`, with every outer element this view computed still cached (but the flattened ones dropped).
+    #[inline(always)]
+    #[must_use]
+    pub fn into_inner(self) -> Reiterator<I> {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// View of every element expanded by `function` into any number of inner elements, flattened into one index space.
+    #[inline(always)]
+    #[must_use]
+    pub const fn flat_map<Function: FnMut(&I::Item) -> Inner, Inner: IntoIterator>(
+        self,
+        function: Function,
+    ) -> FlatMap<I, Function, Inner> {
+        FlatMap {
+            iter: self,
+            function,
+            flat: Vec::new(),
+            starts: Vec::new(),
+            current: None,
+            done: false,
+            index: 0,
+        }
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Fully materialized, immutable, contiguous sequence: what a `Reiterator` becomes once you know you'll never need its source again.
+
+use crate::{
+    cache::{CacheFull, Evicted},
+    Reiterator,
+};
+use ::alloc::{boxed::Box, vec::Vec};
+
+/// Every element of a sequence, computed once and laid out contiguously.
+/// Dereferences to a slice.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Frozen<Item> {
+    /// Every element, in order.
+    items: Box<[Item]>,
+}
+
+impl<Item> Frozen<Item> {
+    /// Return the element at the requested index, provided it's in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn at(&self, index: usize) -> Option<&Item> {
+        self.items.get(index)
+    }
+
+    /// Every element as a contiguous slice.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_slice(&self) -> &[Item] {
+        &self.items
+    }
+
+    /// Give back every element, in order.
+    #[inline(always)]
+    #[must_use]
+    pub fn into_vec(self) -> Vec<Item> {
+        self.items.into_vec()
+    }
+}
+
+impl<Item> From<Vec<Item>> for Frozen<Item> {
+    #[inline(always)]
+    fn from(items: Vec<Item>) -> Self {
+        Self {
+            items: items.into_boxed_slice(),
+        }
+    }
+}
+
+impl<Item> FromIterator<Item> for Frozen<Item> {
+    #[inline(always)]
+    fn from_iter<I: IntoIterator<Item = Item>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<_>>().into()
+    }
+}
+
+impl<Item> core::ops::Deref for Frozen<Item> {
+    type Target = [Item];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+/// Why a `Reiterator` couldn't be frozen.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum FreezeError {
+    /// An element had been evicted, so the frozen buffer couldn't keep its indices intact.
+    Evicted(Evicted),
+    /// `Overflow::Refuse` stopped us before the source ended, so we'd have frozen only part of it.
+    Full(CacheFull),
+}
+
+impl core::fmt::Display for FreezeError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::Evicted(ref evicted) => evicted.fmt(f),
+            Self::Full(ref full) => full.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FreezeError {}
+
+impl From<Evicted> for FreezeError {
+    #[inline(always)]
+    fn from(evicted: Evicted) -> Self {
+        Self::Evicted(evicted)
+    }
+}
+
+impl From<CacheFull> for FreezeError {
+    #[inline(always)]
+    fn from(full: CacheFull) -> Self {
+        Self::Full(full)
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Exhaust the source and move every element (without cloning) into a contiguous `Frozen` buffer.
+    /// Never returns if the source is infinite.
+    /// # Errors
+    /// If any element has been evicted, or `Overflow::Refuse` stopped us before the source ended.
+    #[inline]
+    pub fn freeze(mut self) -> Result<Frozen<I::Item>, FreezeError> {
+        #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+        let len = self.cache.exhaust()?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(len, "freeze");
+        self.cache
+            .into_items()
+            .enumerate()
+            .map(|(index, item)| item.ok_or(FreezeError::Evicted(Evicted { index })))
+            .collect()
+    }
+}
+
+/// Materialize elements `0..len` of an index function in parallel.
+#[cfg(feature = "rayon")]
+#[inline]
+#[must_use]
+pub fn par_freeze<Item: Send, Function: Fn(usize) -> Item + Sync + Send>(
+    len: usize,
+    function: Function,
+) -> Frozen<Item> {
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+    (0..len)
+        .into_par_iter()
+        .map(function)
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// Materialize a source that rayon can split deterministically (e.g. ranges, `Vec`s, slices), in parallel and in order.
+#[cfg(feature = "rayon")]
+#[inline]
+#[must_use]
+pub fn par_freeze_iter<I: rayon::iter::IntoParallelIterator>(iter: I) -> Frozen<I::Item>
+where
+    I::Iter: rayon::iter::IndexedParallelIterator,
+{
+    use rayon::iter::ParallelIterator;
+    iter.into_par_iter().collect::<Vec<_>>().into()
+}
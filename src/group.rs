@@ -0,0 +1,157 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Grouping views: partition a `Reiterator` into runs, each addressable by group index.
+//! Only the elements a group needs are computed, and group boundaries are remembered, so revisiting a group is free.
+
+use crate::{indexed::Indexed, Reiterator};
+use ::alloc::vec::Vec;
+use core::{num::NonZeroUsize, ops::Range};
+
+/// View of consecutive, non-overlapping runs of a fixed length (the last one possibly shorter).
+#[allow(missing_debug_implementations)]
+pub struct Chunks<I: Iterator> {
+    /// Cached elements.
+    iter: Reiterator<I>,
+
+    /// Elements per chunk.
+    size: NonZeroUsize,
+}
+
+impl<I: Iterator> Chunks<I> {
+    /// Indices of the elements in chunk `group`, computing them if we haven't. `None` past the last chunk.
+    #[inline]
+    pub fn range(&mut self, group: usize) -> Option<Range<usize>> {
+        let start = group.checked_mul(self.size.get())?;
+        let end = start.saturating_add(self.size.get());
+        self.iter.force_until(end);
+        let end = end.min(self.iter.cached_len());
+        (start < end).then_some(start..end)
+    }
+
+    /// Every element in chunk `group`, computing them if we haven't, skipping evicted elements. `None` past the last chunk.
+    #[inline]
+    pub fn group(&mut self, group: usize) -> Option<impl Iterator<Item = Indexed<'_, I::Item>>> {
+        let range = self.range(group)?;
+        Some(self.iter.cache.cached_range(range))
+    }
+
+    /// Return the ungrouped `Reiterator`, with everything this view computed still cached.
+    #[inline(always)]
+    #[must_use]
+    pub fn into_inner(self) -> Reiterator<I> {
+        self.iter
+    }
+}
+
+/// View of maximal runs of consecutive elements with equal keys.
+#[allow(missing_debug_implementations)]
+pub struct GroupBy<I: Iterator, KeyFn: FnMut(&I::Item) -> Key, Key: PartialEq> {
+    /// Cached elements.
+    iter: Reiterator<I>,
+
+    /// Run at most once per element.
+    key_fn: KeyFn,
+
+    /// Index of the first element of each group found so far.
+    starts: Vec<usize>,
+
+    /// Key of each group found so far.
+    keys: Vec<Key>,
+
+    /// Number of elements keyed so far.
+    scanned: usize,
+}
+
+impl<I: Iterator, KeyFn: FnMut(&I::Item) -> Key, Key: PartialEq> GroupBy<I, KeyFn, Key> {
+    /// Key the next element. Returns whether there was one.
+    /// An evicted element can't be keyed, so it stays in whatever group it interrupts.
+    #[inline]
+    fn step(&mut self) -> bool {
+        let index = self.scanned;
+        if !self.iter.cache.is_evicted(index) {
+            let Some(item) = self.iter.at(index) else {
+                return false;
+            };
+            let key = (self.key_fn)(item);
+            if self.keys.last() != Some(&key) {
+                self.starts.push(index);
+                self.keys.push(key);
+            }
+        }
+        self.scanned = index.wrapping_add(1);
+        true
+    }
+
+    /// Key elements until we know where group `group` ends (i.e. group `group + 1` starts, or the source ends).
+    /// Returns whether group `group` exists.
+    #[inline]
+    fn scan_through(&mut self, group: usize) -> bool {
+        while self.starts.len() <= group.saturating_add(1) && self.step() {}
+        self.starts.len() > group
+    }
+
+    /// Indices of the elements in group `group`, computing (and keying) as far as its end if we haven't. `None` past the last group.
+    #[inline]
+    pub fn range(&mut self, group: usize) -> Option<Range<usize>> {
+        if !self.scan_through(group) {
+            return None;
+        }
+        let start = *self.starts.get(group)?;
+        let end = self
+            .starts
+            .get(group.wrapping_add(1))
+            .copied()
+            .unwrap_or(self.scanned);
+        Some(start..end)
+    }
+
+    /// Key shared by every element in group `group`, computing as far as its start if we haven't. `None` past the last group.
+    #[inline]
+    pub fn key(&mut self, group: usize) -> Option<&Key> {
+        while self.keys.len() <= group && self.step() {}
+        self.keys.get(group)
+    }
+
+    /// Every element in group `group`, computing them if we haven't, skipping evicted elements. `None` past the last group.
+    #[inline]
+    pub fn group(&mut self, group: usize) -> Option<impl Iterator<Item = Indexed<'_, I::Item>>> {
+        let range = self.range(group)?;
+        Some(self.iter.cache.cached_range(range))
+    }
+
+    /// Return the ungrouped `Reiterator`, with everything this view computed still cached (but the groups dropped).
+    #[inline(always)]
+    #[must_use]
+    pub fn into_inner(self) -> Reiterator<I> {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// View of consecutive runs of `size` elements (the last one possibly shorter), sharing this cache.
+    #[inline(always)]
+    #[must_use]
+    pub const fn chunks(self, size: NonZeroUsize) -> Chunks<I> {
+        Chunks { iter: self, size }
+    }
+
+    /// View of maximal runs of consecutive elements for which `key_fn` agrees, sharing this cache.
+    #[inline(always)]
+    #[must_use]
+    pub const fn group_by<KeyFn: FnMut(&I::Item) -> Key, Key: PartialEq>(
+        self,
+        key_fn: KeyFn,
+    ) -> GroupBy<I, KeyFn, Key> {
+        GroupBy {
+            iter: self,
+            key_fn,
+            starts: Vec::new(),
+            keys: Vec::new(),
+            scanned: 0,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Choose what happens to an unfinished source when a `Reiterator` is dropped. By default, nothing: the source is simply dropped.
+//! Sources that wrap transactions, file handles, or anything else whose completion matters can be drained or handed to a callback instead.
+
+use crate::{cache::Frontier, Reiterator};
+use core::mem::ManuallyDrop;
+
+/// `Reiterator` that runs a callback on its source if it's dropped before the source ends.
+/// Dereferences to the underlying `Reiterator`, so e.g. `.index` and `.restart()` work as usual.
+#[allow(missing_debug_implementations)]
+pub struct OnDrop<I: Iterator, Callback: FnOnce(Frontier, I)> {
+    /// Underlying cache and cursor. Only ever taken by `into_inner` or `drop`.
+    reiterator: ManuallyDrop<Reiterator<I>>,
+
+    /// What to do with an unfinished source. Only ever taken by `into_inner` or `drop`.
+    callback: ManuallyDrop<Callback>,
+}
+
+impl<I: Iterator, Callback: FnOnce(Frontier, I)> OnDrop<I, Callback> {
+    /// Return the underlying `Reiterator` without running the callback, now or ever.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Reiterator<I> {
+        let mut this = ManuallyDrop::new(self);
+        #[allow(unsafe_code)]
+        // SAFETY: `this` is never dropped, so each field is taken exactly once.
+        unsafe {
+            ManuallyDrop::drop(&mut this.callback);
+            ManuallyDrop::take(&mut this.reiterator)
+        }
+    }
+}
+
+impl<I: Iterator, Callback: FnOnce(Frontier, I)> core::ops::Deref for OnDrop<I, Callback> {
+    type Target = Reiterator<I>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.reiterator
+    }
+}
+
+impl<I: Iterator, Callback: FnOnce(Frontier, I)> core::ops::DerefMut for OnDrop<I, Callback> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.reiterator
+    }
+}
+
+impl<I: Iterator, Callback: FnOnce(Frontier, I)> Drop for OnDrop<I, Callback> {
+    #[inline]
+    fn drop(&mut self) {
+        #[allow(unsafe_code)]
+        // SAFETY: We're being dropped, so neither field is ever touched again.
+        let (reiterator, callback) = unsafe {
+            (
+                ManuallyDrop::take(&mut self.reiterator),
+                ManuallyDrop::take(&mut self.callback),
+            )
+        };
+        if reiterator.is_exhausted() || reiterator.cache.is_poisoned() {
+            return;
+        }
+        let frontier = reiterator.frontier();
+        let (_, source) = reiterator.cache.into_items_and_source();
+        callback(frontier, source);
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// If this is dropped before the source ends, call `callback` with how far we got and the source itself
+    /// (which has produced everything before the frontier), e.g. to commit or roll back a transaction it wraps.
+    /// Cached elements are dropped first. Nothing happens if the source has ended or panicked.
+    #[inline(always)]
+    #[must_use]
+    pub const fn on_drop<Callback: FnOnce(Frontier, I)>(
+        self,
+        callback: Callback,
+    ) -> OnDrop<I, Callback> {
+        OnDrop {
+            reiterator: ManuallyDrop::new(self),
+            callback: ManuallyDrop::new(callback),
+        }
+    }
+
+    /// If this is dropped before the source ends, run the source to completion (without caching anything),
+    /// for sources whose side effects on completion matter. Never finishes dropping if the source is infinite.
+    #[inline(always)]
+    #[must_use]
+    pub const fn drain_on_drop(self) -> OnDrop<I, fn(Frontier, I)> {
+        self.on_drop(|_, source| source.for_each(drop))
+    }
+}
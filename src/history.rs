@@ -0,0 +1,131 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Event-sourcing helper: indexed access to the last K events of a live channel, plus a cursor that follows the tail.
+
+use crate::indexed::Indexed;
+use core::num::NonZeroUsize;
+use std::{collections::VecDeque, sync::mpsc};
+
+/// Bounded history of a live event feed.
+/// Producers send events through the `Sender` returned by `new`; every event gets a permanent index,
+/// but only the most recent `capacity` events are kept around.
+#[derive(Debug)]
+#[allow(clippy::partial_pub_fields)]
+pub struct HistoryBuffer<Item> {
+    /// Live feed of new events.
+    receiver: mpsc::Receiver<Item>,
+
+    /// The most recent events, oldest first.
+    window: VecDeque<Item>,
+
+    /// Maximum number of events to keep. Never zero, or `next` would have nothing to hand out.
+    capacity: NonZeroUsize,
+
+    /// Index of the oldest event still in `window`.
+    start: usize,
+
+    /// Cursor for `next`. Safe to edit! If it points at an event that's been dropped, `next` skips ahead to the oldest one we still have.
+    pub index: usize,
+}
+
+impl<Item> HistoryBuffer<Item> {
+    /// Make an empty history keeping at most `capacity` events, along with the `Sender` producers should push events into.
+    #[inline]
+    #[must_use]
+    pub fn new(capacity: NonZeroUsize) -> (mpsc::Sender<Item>, Self) {
+        let (sender, receiver) = mpsc::channel();
+        (
+            sender,
+            Self {
+                receiver,
+                window: VecDeque::with_capacity(capacity.get()),
+                capacity,
+                start: 0,
+                index: 0,
+            },
+        )
+    }
+
+    /// Append one event, dropping the oldest if we're full.
+    #[inline]
+    fn push(&mut self, item: Item) {
+        if self.window.len() >= self.capacity.get() {
+            drop(self.window.pop_front());
+            self.start = self.start.saturating_add(1);
+        }
+        self.window.push_back(item);
+    }
+
+    /// Take in every event that's already been sent, without blocking. Returns how many arrived.
+    #[inline]
+    pub fn poll(&mut self) -> usize {
+        let mut received = 0_usize;
+        while let Ok(item) = self.receiver.try_recv() {
+            self.push(item);
+            received = received.saturating_add(1);
+        }
+        received
+    }
+
+    /// Total number of events ever received (i.e. the index the next event will have).
+    #[inline(always)]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.start.saturating_add(self.window.len())
+    }
+
+    /// Whether we've never received anything.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the event at `index` was received but has since been dropped to make room.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_evicted(&self, index: usize) -> bool {
+        index < self.start
+    }
+
+    /// Return the event at `index` if we received it and still have it. Never blocks.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&Item> {
+        let _ = self.poll();
+        self.window.get(index.checked_sub(self.start)?)
+    }
+
+    /// The most recent event, if any. Never blocks.
+    #[inline]
+    #[must_use]
+    pub fn latest(&mut self) -> Option<Indexed<'_, Item>> {
+        let _ = self.poll();
+        let index = self.len().checked_sub(1)?;
+        Some(Indexed {
+            index,
+            value: self.window.back()?,
+        })
+    }
+
+    /// Return the event at the cursor and advance it, blocking until that event arrives if the cursor is at the live tail.
+    /// Returns `None` only once every `Sender` has been dropped and the cursor has caught up.
+    #[inline]
+    pub fn next(&mut self) -> Option<Indexed<'_, Item>> {
+        let _ = self.poll();
+        while self.index >= self.len() {
+            let item = self.receiver.recv().ok()?;
+            self.push(item);
+        }
+        let index = self.index.max(self.start);
+        self.index = index.checked_add(1)?;
+        Some(Indexed {
+            index,
+            value: self.window.get(index.checked_sub(self.start)?)?,
+        })
+    }
+}
@@ -9,7 +9,7 @@
 /// A value as well as how many elements an iterator spat out before it.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[allow(clippy::exhaustive_structs, clippy::single_char_lifetime_names)]
-pub struct Indexed<'value, Value> {
+pub struct Indexed<'value, Value: ?Sized> {
     /// Number of elements an iterator spat out before this one.
     pub index: usize,
 
@@ -17,11 +17,115 @@ pub struct Indexed<'value, Value> {
     pub value: &'value Value,
 }
 
+/// How to number elements when reporting them to a person: storage is always 0-based, but editors and line numbers usually aren't.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_enums)]
+pub enum IndexBase {
+    /// The first element is #0, same as its index.
+    #[default]
+    Zero,
+    /// The first element is #1.
+    One,
+}
+
+#[cfg(feature = "unstable")]
+impl IndexBase {
+    /// Number to show for the element at 0-based `index`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn report(self, index: usize) -> usize {
+        match self {
+            Self::Zero => index,
+            Self::One => index.saturating_add(1),
+        }
+    }
+
+    /// 0-based index of the element a person called `reported` (`None` for #0 when counting from one).
+    #[inline(always)]
+    #[must_use]
+    pub const fn index(self, reported: usize) -> Option<usize> {
+        match self {
+            Self::Zero => Some(reported),
+            Self::One => reported.checked_sub(1),
+        }
+    }
+}
+
+/// Shows as `index: value`, 0-based. See `Indexed::display_with` for 1-based.
+#[cfg(feature = "unstable")]
+impl<Value: ?Sized + core::fmt::Display> core::fmt::Display for Indexed<'_, Value> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.index, self.value)
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl<'value, Value: ?Sized> Indexed<'value, Value> {
+    /// Show as `index: value`, numbering from `base`. Only affects what's printed.
+    #[inline(always)]
+    #[must_use]
+    pub const fn display_with(self, base: IndexBase) -> DisplayIndexed<'value, Value> {
+        DisplayIndexed {
+            indexed: self,
+            base,
+        }
+    }
+}
+
+/// `Indexed` shown with a chosen `IndexBase`. See `Indexed::display_with`.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayIndexed<'value, Value: ?Sized> {
+    /// What to show.
+    indexed: Indexed<'value, Value>,
+    /// How to number it.
+    base: IndexBase,
+}
+
+#[cfg(feature = "unstable")]
+impl<Value: ?Sized + core::fmt::Display> core::fmt::Display for DisplayIndexed<'_, Value> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}: {}",
+            self.base.report(self.indexed.index),
+            self.indexed.value,
+        )
+    }
+}
+
+/// An owned value as well as how many elements an iterator spat out before it.
+/// Unlike `Indexed`, this has no lifetime, so it can be collected, sent across threads, or stored anywhere.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct OwnedIndexed<Value> {
+    /// Number of elements an iterator spat out before this one.
+    pub index: usize,
+
+    /// Output of an iterator.
+    pub value: Value,
+}
+
+#[cfg(feature = "unstable")]
+impl<Value: Clone> From<Indexed<'_, Value>> for OwnedIndexed<Value> {
+    #[inline(always)]
+    fn from(indexed: Indexed<'_, Value>) -> Self {
+        Self {
+            index: indexed.index,
+            value: indexed.value.clone(),
+        }
+    }
+}
+
 /// Return the index from an `Indexed` item. Consumes its argument: written with `.map(index)` in mind.
 #[allow(clippy::needless_pass_by_value)]
 #[inline(always)]
 #[must_use]
-pub const fn index<Value>(indexed: Indexed<'_, Value>) -> usize {
+pub const fn index<Value: ?Sized>(indexed: Indexed<'_, Value>) -> usize {
     indexed.index
 }
 
@@ -29,7 +133,7 @@ pub const fn index<Value>(indexed: Indexed<'_, Value>) -> usize {
 #[allow(clippy::needless_pass_by_value)]
 #[inline(always)]
 #[must_use]
-pub const fn value<Value>(indexed: Indexed<'_, Value>) -> &Value {
+pub const fn value<Value: ?Sized>(indexed: Indexed<'_, Value>) -> &Value {
     indexed.value
 }
 
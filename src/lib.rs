@@ -43,7 +43,9 @@
 //! assert_eq!(iter.at(3), None);
 //! ```
 
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![cfg_attr(feature = "nightly", feature(trusted_len), allow(unstable_features))]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 #![deny(warnings)]
 #![warn(
     clippy::all,
@@ -109,12 +111,143 @@
 
 extern crate alloc;
 
+#[cfg(feature = "unstable")]
+use ::alloc::vec::Vec;
+
+#[cfg(feature = "unstable")]
+pub mod aggregate;
+#[cfg(all(feature = "address-audit", feature = "unstable"))]
+pub mod audit;
+#[cfg(feature = "unstable")]
+pub mod back;
+#[cfg(feature = "unstable")]
+pub mod bisect;
+#[cfg(feature = "unstable")]
+pub mod builder;
 pub mod cache;
+#[cfg(all(feature = "std", feature = "unstable"))]
+pub mod catch;
+#[cfg(feature = "unstable")]
+pub mod chain;
+#[cfg(all(feature = "std", feature = "unstable"))]
+pub mod channel;
+#[cfg(feature = "unstable")]
+pub mod checkpoint;
+#[cfg(feature = "unstable")]
+pub mod checksum;
+#[cfg(feature = "unstable")]
+pub mod contiguous;
+#[cfg(feature = "unstable")]
+pub mod convert;
+#[cfg(feature = "unstable")]
+pub mod cow;
+#[cfg(all(feature = "cursor-history", feature = "unstable"))]
+pub mod cursor;
+#[cfg(feature = "unstable")]
+pub mod cycle;
+#[cfg(feature = "unstable")]
+pub mod dedup;
+#[cfg(feature = "unstable")]
+pub mod delimiter;
+#[cfg(feature = "unstable")]
+pub mod dynamic;
+#[cfg(feature = "unstable")]
+pub mod exact;
+#[cfg(feature = "unstable")]
+pub mod filter;
+#[cfg(feature = "unstable")]
+pub mod find;
+#[cfg(feature = "unstable")]
+pub mod flat;
+#[cfg(feature = "unstable")]
+pub mod frozen;
+#[cfg(feature = "unstable")]
+pub mod group;
+#[cfg(feature = "unstable")]
+pub mod guard;
+#[cfg(all(feature = "std", feature = "unstable"))]
+pub mod history;
 pub mod indexed;
+#[cfg(feature = "unstable")]
+pub mod memo;
+#[cfg(feature = "unstable")]
+pub mod offset;
+#[cfg(feature = "unstable")]
+pub mod prefetch;
+#[cfg(all(feature = "profiling", feature = "unstable"))]
+pub mod profile;
+#[cfg(feature = "unstable")]
+pub mod rc;
+#[cfg(feature = "unstable")]
+pub mod reborrow;
+#[cfg(feature = "unstable")]
+pub mod refs;
+#[cfg(feature = "unstable")]
+pub mod replay;
+#[cfg(all(feature = "rand", feature = "unstable"))]
+pub mod sample;
+#[cfg(feature = "unstable")]
+pub mod scan;
+#[cfg(all(feature = "std", feature = "unstable"))]
+pub mod scoped;
+#[cfg(feature = "unstable")]
+pub mod sealed;
+#[cfg(feature = "unstable")]
+pub mod search;
+#[cfg(all(feature = "shm", unix))]
+pub mod shared;
+#[cfg(feature = "unstable")]
+pub mod skip;
+#[cfg(feature = "unstable")]
+pub mod snapshot;
+#[cfg(feature = "unstable")]
+pub mod sorted;
+#[cfg(feature = "unstable")]
+pub mod subscribe;
+#[cfg(feature = "unstable")]
+pub mod take;
+#[cfg(all(feature = "testing", feature = "unstable"))]
+pub mod testing;
+#[cfg(all(feature = "std", feature = "unstable"))]
+pub mod timeout;
+#[cfg(all(feature = "unicode", feature = "unstable"))]
+pub mod unicode;
+#[cfg(feature = "unstable")]
+mod view;
+#[cfg(feature = "unstable")]
+pub mod zip;
 
 #[cfg(test)]
 mod test;
 
+/// Everything that could evict on its own, set aside by `Reiterator::suspend_eviction`.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Suspended {
+    /// Cap and window, as they were.
+    cache: cache::Suspended,
+    /// Whether `auto_tune`'s window was already on hold.
+    #[cfg(feature = "profiling")]
+    tuning: bool,
+}
+
+/// What a method just did with the cursor, for `Reiterator::track_cursor`.
+#[derive(Clone, Copy, Debug)]
+enum CursorUse {
+    /// Moved it to wherever `index` is now.
+    Moved,
+    /// `get`/`peek` is about to read it.
+    Get,
+    /// `peek_ahead` is about to read past it.
+    #[cfg(feature = "unstable")]
+    PeekAhead,
+    /// `next` is about to read from it.
+    Next,
+    /// `prev` is about to step back from it.
+    #[cfg(feature = "unstable")]
+    Prev,
+}
+
 /// Caching repeatable iterator that only ever calculates each element once.
 /// NOTE that if the iterator is not referentially transparent (i.e. pure, e.g. mutable state), this *will not necessarily work*!
 /// We replace a call to a previously evaluated index with the value we already made, so side effects will not show up at all.
@@ -128,6 +261,33 @@ pub struct Reiterator<I: Iterator> {
     ///   - If the index is out of bounds, we return `None` (after exhausting the iterator: it's not necessarily a fixed size, so there's only one way to find out).
     /// Note that this iterator is lazy, so assigning an index doesn't mean that the value at that index has been calculated.
     pub index: usize,
+
+    /// How many elements `next_back` has taken from the end. Safe to edit, just like `index`.
+    #[cfg(feature = "unstable")]
+    pub back_index: usize,
+
+    /// Callbacks waiting on blocks of newly cached elements.
+    #[cfg(feature = "unstable")]
+    subscriptions: Vec<subscribe::Subscription<I::Item>>,
+
+    /// How hard `get`/`next` try to compute elements past the end of the cache.
+    #[cfg(feature = "unstable")]
+    probe: cache::Probe,
+
+    /// Whether the last cursor read gave up because of `probe`.
+    #[cfg(feature = "unstable")]
+    budgeted: bool,
+
+    /// How far `next`/`lazy_next` move the cursor at a time.
+    stride: core::num::NonZeroUsize,
+
+    /// Recent cursor movements, if anyone asked.
+    #[cfg(all(feature = "cursor-history", feature = "unstable"))]
+    cursor_history: cursor::CursorHistory,
+
+    /// How we've been read so far.
+    #[cfg(all(feature = "profiling", feature = "unstable"))]
+    access_profile: profile::AccessProfile,
 }
 
 impl<I: Iterator> Reiterator<I> {
@@ -135,12 +295,78 @@ impl<I: Iterator> Reiterator<I> {
     #[inline(always)]
     pub fn new<II: IntoIterator<IntoIter = I>>(into_iter: II) -> Self {
         use cache::Cached;
+        Self::from_cache(into_iter.cached())
+    }
+
+    /// Like `new`, but with room for `capacity` elements before the cache has to reallocate.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn with_capacity<II: IntoIterator<IntoIter = I>>(into_iter: II, capacity: usize) -> Self {
+        Self::from_cache(cache::Cache::with_capacity(into_iter, capacity))
+    }
+
+    /// Wrap an existing cache with the cursor at zero.
+    #[inline(always)]
+    pub(crate) fn from_cache(cache: cache::Cache<I>) -> Self {
         Self {
-            cache: into_iter.cached(),
+            cache,
             index: 0,
+            #[cfg(feature = "unstable")]
+            back_index: 0,
+            #[cfg(feature = "unstable")]
+            subscriptions: alloc::vec![],
+            #[cfg(feature = "unstable")]
+            probe: cache::Probe::Exhaust,
+            #[cfg(feature = "unstable")]
+            budgeted: false,
+            stride: core::num::NonZeroUsize::MIN,
+            #[cfg(all(feature = "cursor-history", feature = "unstable"))]
+            cursor_history: cursor::CursorHistory::default(),
+            #[cfg(all(feature = "profiling", feature = "unstable"))]
+            access_profile: profile::AccessProfile::default(),
         }
     }
 
+    /// Index of the element `get`/`next` will return. Equivalent to reading `.index`, but usable in `const` contexts.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Number of elements computed so far (including any since evicted).
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn cached_len(&self) -> usize {
+        self.cache.cached_len()
+    }
+
+    /// Whether nothing has been computed yet. Never computes anything.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Whether the source has ended, i.e. everything there is has been computed. Never computes anything.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_exhausted(&self) -> bool {
+        self.cache.known_len().is_some()
+    }
+
+    /// Total number of elements (including any evicted), once the source has ended. Never computes anything.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn known_len(&self) -> Option<usize> {
+        self.cache.known_len()
+    }
+
     /// Set the index to zero. Literal drop-in equivalent for `.index = 0`, always inlined. Clearer, I guess.
     #[inline(always)]
     pub fn restart(&mut self) {
@@ -151,14 +377,52 @@ impl<I: Iterator> Reiterator<I> {
     #[inline]
     #[must_use]
     pub fn at(&mut self, index: usize) -> Option<&I::Item> {
-        self.cache.get(index).map(|item| {
-            let pointer: *const _ = item;
-            #[allow(unsafe_code)]
-            // SAFETY: Known lifetime.
-            unsafe {
-                &*pointer
-            }
-        })
+        #[cfg(all(feature = "profiling", feature = "unstable"))]
+        {
+            self.access_profile.record(index, self.cache.cached_len());
+            self.apply_tuning();
+        }
+        #[cfg(feature = "unstable")]
+        {
+            let pointer = self.cache.get(index).map(|item| -> *const I::Item { item });
+            self.notify();
+            pointer.map(|pointer| {
+                #[allow(unsafe_code)]
+                // SAFETY: Elements are boxed, and `notify` never moves or evicts anything.
+                unsafe {
+                    &*pointer
+                }
+            })
+        }
+        #[cfg(not(feature = "unstable"))]
+        self.cache.get(index)
+    }
+
+    /// Like `at`, but for every index in `range`: compute up to `range.end` (stopping early if the source ends),
+    /// then yield every element in `range`, skipping evicted elements. Doesn't move the cursor.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn at_range(
+        &mut self,
+        range: core::ops::Range<usize>,
+    ) -> impl Iterator<Item = indexed::Indexed<'_, I::Item>> {
+        self.force_until(range.end);
+        self.cache.cached_range(range)
+    }
+
+    /// Like `at` for each of `indices`, in the order given, but computing up to the largest one in a single pass.
+    /// Out-of-bounds and evicted elements are `None`. Doesn't move the cursor.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn at_many(&mut self, indices: &[usize]) -> Vec<Option<&I::Item>> {
+        if let Some(&max) = indices.iter().max() {
+            self.force_until(max.saturating_add(1));
+        }
+        indices
+            .iter()
+            .map(|&index| self.cache.cached(index))
+            .collect()
     }
 
     /// Return the current element or compute it if we haven't, provided it's in bounds.
@@ -167,28 +431,570 @@ impl<I: Iterator> Reiterator<I> {
     #[inline(always)]
     #[must_use]
     pub fn get(&mut self) -> Option<indexed::Indexed<'_, I::Item>> {
+        self.track_cursor(CursorUse::Get);
+        #[cfg(feature = "unstable")]
+        if !self.probe(self.index) {
+            return None;
+        }
         Some(indexed::Indexed {
             index: self.index,
             value: self.at(self.index)?,
         })
     }
 
-    /// Advance the index without computing the corresponding value.
+    /// Return the current element without advancing, exactly like `get`. Named for parsers.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub fn peek(&mut self) -> Option<indexed::Indexed<'_, I::Item>> {
+        self.get()
+    }
+
+    /// Return the element `offset` positions past the cursor (in the dense index space) without moving the cursor.
+    /// `peek_ahead(0)` is `peek()`.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn peek_ahead(&mut self, offset: usize) -> Option<indexed::Indexed<'_, I::Item>> {
+        self.track_cursor(CursorUse::PeekAhead);
+        let index = self.index.checked_add(offset)?;
+        if !self.probe(index) {
+            return None;
+        }
+        Some(indexed::Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+
+    /// Advance the index (by the stride; see `step_by`) without computing the corresponding value.
     #[inline(always)]
     pub fn lazy_next(&mut self) -> Option<usize> {
-        self.index.checked_add(1).map(|incr| {
+        self.index.checked_add(self.stride.get()).map(|incr| {
             self.index = incr;
+            self.track_cursor(CursorUse::Moved);
             incr
         })
     }
 
     /// Like `Iterator::next` but with a dependent lifetime.
+    /// Silently skips over evicted elements (their indices are still counted).
     #[inline(always)]
+    #[cfg_attr(not(feature = "unstable"), allow(clippy::never_loop))]
     pub fn next(&mut self) -> Option<indexed::Indexed<'_, I::Item>> {
-        let index = self.index;
-        let _ = self.lazy_next()?;
-        self.at(index)
-            .map(|value| indexed::Indexed { index, value })
+        self.track_cursor(CursorUse::Next);
+        loop {
+            let index = self.index;
+            let _ = self.lazy_next()?;
+            #[cfg(feature = "unstable")]
+            {
+                if self.cache.is_evicted(index) {
+                    continue;
+                }
+                if !self.probe(index) {
+                    return None;
+                }
+            }
+            return self
+                .at(index)
+                .map(|value| indexed::Indexed { index, value });
+        }
+    }
+
+    /// Move the index back (by the stride; see `step_by`) without computing anything. `None` (without moving) if that would underflow.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn step_back(&mut self) -> Option<usize> {
+        self.index.checked_sub(self.stride.get()).map(|decr| {
+            self.index = decr;
+            self.track_cursor(CursorUse::Moved);
+            decr
+        })
+    }
+
+    /// Move the cursor `offset` elements forward (or back, if negative) without computing anything.
+    /// Returns the new index, or `None` (without moving) if that would overflow or underflow.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn seek_relative(&mut self, offset: isize) -> Option<usize> {
+        let index = self.index.checked_add_signed(offset)?;
+        self.index = index;
+        self.track_cursor(CursorUse::Moved);
+        Some(index)
+    }
+
+    /// Like `Iterator::advance_by`, but keeps everything it steps over cached: move the cursor `n` elements forward,
+    /// computing each one, or (if the source ends first) move to the end and report how many steps were left over.
+    /// Evicted elements count as steps taken. If `Overflow::Refuse` stops us first, the cursor stops at the first element we couldn't compute.
+    /// # Errors
+    /// Number of steps not taken if the source ended (or `Overflow::Refuse` stopped us) first.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), core::num::NonZeroUsize> {
+        let start = self.index;
+        let target = start.saturating_add(n);
+        let reached = match target.checked_sub(1) {
+            Some(last) if last >= start => {
+                if self.at(last).is_some() || self.cache.is_evicted(last) {
+                    target
+                } else {
+                    self.cache
+                        .known_len()
+                        .unwrap_or_else(|| self.cache.cached_len())
+                        .clamp(start, target)
+                }
+            }
+            _ => start,
+        };
+        self.index = reached;
+        self.track_cursor(CursorUse::Moved);
+        core::num::NonZeroUsize::new(n.wrapping_sub(reached.wrapping_sub(start)))
+            .map_or(Ok(()), Err)
+    }
+
+    /// Like `Iterator::nth`: skip `n` elements past the cursor, then act like `next`.
+    /// Unlike `Iterator::nth`, nothing is discarded: everything skipped is computed and stays cached, so rewinding still works.
+    /// If the source ends first, returns `None`.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn nth(&mut self, n: usize) -> Option<indexed::Indexed<'_, I::Item>> {
+        self.advance_by(n).ok()?;
+        self.next()
+    }
+
+    /// Mirror image of `next`: step back, then return the element under the cursor, so `next` followed by `prev` returns the same element twice.
+    /// Silently skips over evicted elements, and returns `None` at the start.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn prev(&mut self) -> Option<indexed::Indexed<'_, I::Item>> {
+        self.track_cursor(CursorUse::Prev);
+        loop {
+            let index = self.step_back()?;
+            if !self.cache.is_evicted(index) {
+                return self
+                    .at(index)
+                    .map(|value| indexed::Indexed { index, value });
+            }
+        }
+    }
+
+    /// Make `next` advance the cursor `step` elements at a time instead of one.
+    /// Indices (and `at`) still refer to the original, dense sequence.
+    /// Elements stepped over are still computed and cached along the way, since the source is sequential.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn step_by(mut self, step: core::num::NonZeroUsize) -> Self {
+        self.stride = step;
+        self
+    }
+
+    /// How far `next` moves the cursor at a time.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn stride(&self) -> core::num::NonZeroUsize {
+        self.stride
+    }
+
+    /// Stable handle to the element at `index`, computing it if we haven't, provided it's in bounds (and not evicted).
+    /// Store these instead of raw indices to find out (via `resolve`) when an element has gone away.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn id(&mut self, index: usize) -> Option<cache::ElementId> {
+        let _ = self.at(index)?;
+        self.cache.id(index)
+    }
+
+    /// The element `id` was issued for, unless it's since been evicted or invalidated. Never computes anything.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub fn resolve(&self, id: cache::ElementId) -> Option<&I::Item> {
+        self.cache.resolve(id)
+    }
+
+    /// Resolve a batch of IDs at once, reporting which are still live and which have been evicted or invalidated.
+    /// Never computes anything.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub fn resolve_many(&self, ids: &[cache::ElementId]) -> cache::Resolved<'_, I::Item> {
+        self.cache.resolve_many(ids)
+    }
+
+    /// Choose how the cache's storage grows when it runs out of room (by default, however `Vec` does).
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn set_growth(&mut self, growth: cache::Growth) {
+        self.cache.set_growth(growth);
+    }
+
+    /// Number of elements we can cache before storage has to grow again.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.cache.capacity()
+    }
+
+    /// Never hold more than `max_cached` elements (not counting evicted ones) at once, doing `overflow` instead,
+    /// e.g. so that a stray `at(usize::MAX)` on an infinite source can't eat all memory.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn set_max_cached(&mut self, max_cached: usize, overflow: cache::Overflow) {
+        self.cache.set_max_cached(max_cached, overflow);
+    }
+
+    /// Like `at`, but if the element is out of reach only because `Overflow::Refuse` stopped us computing it, say so.
+    /// # Errors
+    /// If computing the element would have put more than `max_cached` elements in the cache.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn checked_at(&mut self, index: usize) -> Result<Option<&I::Item>, cache::CacheFull> {
+        let pointer = self.at(index).map(|item| -> *const I::Item { item });
+        if let Some(full) = pointer
+            .is_none()
+            .then(|| self.cache.refused(index))
+            .flatten()
+        {
+            return Err(full);
+        }
+        Ok(pointer.map(|pointer| {
+            #[allow(unsafe_code)]
+            // SAFETY: Elements are boxed, and `refused` only reads the cache, so nothing moved.
+            unsafe {
+                &*pointer
+            }
+        }))
+    }
+
+    /// Keep only the `window` most recently computed elements, evicting older ones as new ones arrive (and anything already cached
+    /// that's out of the window right away), so a long-running stream holds a bounded number of elements.
+    /// Read with `at_or_evicted` to tell an element that fell out of the window apart from one past the end. `None` to stop evicting.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn set_window(&mut self, window: Option<core::num::NonZeroUsize>) {
+        self.cache.set_window(window);
+    }
+
+    /// Like `at`, but if the element has already been evicted (e.g. fallen out of the window from `set_window`), say so.
+    /// # Errors
+    /// If the element was computed but has since been evicted.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn at_or_evicted(&mut self, index: usize) -> Result<Option<&I::Item>, cache::Evicted> {
+        if self.cache.is_evicted(index) {
+            return Err(cache::Evicted { index });
+        }
+        let pointer = self.at(index).map(|item| -> *const I::Item { item });
+        if pointer.is_none() && self.cache.is_evicted(index) {
+            return Err(cache::Evicted { index });
+        }
+        Ok(pointer.map(|pointer| {
+            #[allow(unsafe_code)]
+            // SAFETY: Elements are boxed, and `is_evicted` only reads the cache, so nothing moved.
+            unsafe {
+                &*pointer
+            }
+        }))
+    }
+
+    /// Stop anything evicting on its own (`Overflow::Evict` acts like `Overflow::Refuse`, and neither `set_window`'s nor `auto_tune`'s window slides)
+    /// until `resume_eviction`, e.g. while references we've handed out outlive what the borrow checker can see.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub(crate) fn suspend_eviction(&mut self) -> Suspended {
+        Suspended {
+            cache: self.cache.suspend_eviction(),
+            #[cfg(feature = "profiling")]
+            tuning: core::mem::replace(&mut self.access_profile.suspended, true),
+        }
+    }
+
+    /// Put back what `suspend_eviction` set aside, without evicting anything right away: anything owed is evicted on a later read.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub(crate) fn resume_eviction(&mut self, suspended: Suspended) {
+        self.cache.resume_eviction(suspended.cache);
+        #[cfg(feature = "profiling")]
+        {
+            self.access_profile.suspended = suspended.tuning;
+        }
+    }
+
+    /// Make room for at least `additional` more elements past those already cached, e.g. once you know how long the input is.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.cache.reserve(additional);
+    }
+
+    /// Give back any storage past the elements already cached, e.g. after `truncate`.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn shrink_to_fit(&mut self) {
+        self.cache.shrink_to_fit();
+    }
+
+    /// Choose how hard `get`/`next` try to compute elements past the end of the cache (by default, as hard as it takes).
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn set_probe(&mut self, probe: cache::Probe) {
+        self.probe = probe;
+    }
+
+    /// Tell `cursor_history` (if we're recording) what a method just did with the cursor.
+    /// Every cursor read and move goes through here, so this is the only place that needs the feature gate.
+    #[inline(always)]
+    #[cfg_attr(
+        not(all(feature = "cursor-history", feature = "unstable")),
+        allow(unused_variables, clippy::unused_self)
+    )]
+    fn track_cursor(&mut self, used: CursorUse) {
+        #[cfg(all(feature = "cursor-history", feature = "unstable"))]
+        self.cursor_history.track(self.index, used);
+    }
+
+    /// Whether the current `Probe` policy lets a cursor-driven read at `index` go ahead.
+    /// If the policy allows computing only part of the way there, compute that much anyway.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    fn probe(&mut self, index: usize) -> bool {
+        let allowed = self.probe_uncounted(index);
+        self.budgeted = !allowed;
+        allowed
+    }
+
+    /// `probe` without remembering the outcome for `state`.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    fn probe_uncounted(&mut self, index: usize) -> bool {
+        let cached = self.cache.cached_len();
+        if index < cached {
+            return true;
+        }
+        match self.probe {
+            cache::Probe::Exhaust => true,
+            cache::Probe::CacheOnly => false,
+            cache::Probe::AtMost(limit) => {
+                let Some(reachable) = cached.checked_add(limit) else {
+                    return true;
+                };
+                if index < reachable {
+                    return true;
+                }
+                if let Some(last) = reachable.checked_sub(1) {
+                    let _ = self.at(last);
+                }
+                false
+            }
+        }
+    }
+
+    /// Where the stream is in its life. Never computes anything.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub const fn state(&self) -> cache::StreamState {
+        let cached = self.cache.cached_len();
+        if let Some(len) = self.cache.known_len() {
+            cache::StreamState::Exhausted { len }
+        } else if self.cache.is_poisoned() {
+            cache::StreamState::Poisoned { cached }
+        } else if self.budgeted {
+            cache::StreamState::Budgeted { cached }
+        } else if cached == 0 {
+            cache::StreamState::Unstarted
+        } else {
+            cache::StreamState::Partial { cached }
+        }
+    }
+
+    /// Every element computed so far, in order, skipping evicted elements.
+    /// Never computes anything, so it's safe to call on expensive or infinite sources. Doesn't move the cursor.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn cached_iter(&self) -> impl Iterator<Item = indexed::Indexed<'_, I::Item>> {
+        self.cache.cached_range(0..usize::MAX)
+    }
+
+    /// Cache `item` as the next element, ahead of anything the source has yet to produce (e.g. a value computed elsewhere and received over the network).
+    /// Everything not yet computed moves back by one index. Counts toward `set_max_cached` and `set_window` like any other element.
+    /// # Errors
+    /// Hands `item` back if `Overflow::Refuse` won't let us cache it.
+    /// # Panics
+    /// If the cache is full and the policy is `Overflow::Panic`.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn push_front_of_source(&mut self, item: I::Item) -> Result<(), I::Item> {
+        self.cache.push_front_of_source(item)?;
+        self.notify();
+        Ok(())
+    }
+
+    /// Keep everything cached, but compute anything new from `into_iter` instead (e.g. the rest of an input that arrived late).
+    /// Indices continue where the cache leaves off. Returns the old source.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn replace_source<II: IntoIterator<IntoIter = I>>(&mut self, into_iter: II) -> I {
+        self.cache.replace_source(into_iter)
+    }
+
+    /// Drop everything cached and start over from `into_iter`, returning the old source.
+    /// The cursor stays where it is; every `ElementId` issued so far stops resolving, and subscribers start over too.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn reset_source<II: IntoIterator<IntoIter = I>>(&mut self, into_iter: II) -> I {
+        self.truncate(0, into_iter)
+    }
+
+    /// Drop every element from index `len` on (e.g. because the source turned out not to be referentially transparent past there)
+    /// and recompute them on demand from `into_iter`, which must produce the element at `len` first. Returns the old source.
+    /// The cursor stays where it is; `ElementId`s for dropped elements stop resolving, and subscribers see the new elements.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn truncate<II: IntoIterator<IntoIter = I>>(&mut self, len: usize, into_iter: II) -> I {
+        self.rewind_subscriptions(len);
+        self.budgeted = false;
+        #[cfg(feature = "profiling")]
+        self.access_profile.rewind(len);
+        self.cache.truncate(len, into_iter)
+    }
+
+    /// Drop everything cached and recompute on demand from `into_iter`. Same as `reset_source`.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn clear<II: IntoIterator<IntoIter = I>>(&mut self, into_iter: II) -> I {
+        self.truncate(0, into_iter)
+    }
+
+    /// Mark how far we've computed so far, to later ask `delta_since` what's new.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub const fn frontier(&self) -> cache::Frontier {
+        self.cache.frontier()
+    }
+
+    /// Every element computed since `frontier` was captured, in order, without computing anything new.
+    /// Observers can poll with this (then capture a new `frontier`) instead of tracking indices themselves.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn delta_since(
+        &self,
+        frontier: cache::Frontier,
+    ) -> impl Iterator<Item = indexed::Indexed<'_, I::Item>> {
+        self.cache.cached_since(frontier)
+    }
+
+    /// Drop every cached element for which `predicate` returns `false` without disturbing anyone's indices.
+    /// Evicted elements are skipped by `next` and return `None` from `at`/`get`; they are never recomputed.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn retain_cached<Predicate: FnMut(&I::Item) -> bool>(&mut self, predicate: Predicate) {
+        self.cache.retain_cached(predicate);
+    }
+
+    /// Bounds on how many more elements `next` will return, like `Iterator::size_hint`.
+    /// Exact whenever the source's own `size_hint` is exact, unless a `Probe` budget or `Overflow::Refuse` could cut reads short,
+    /// in which case only elements already cached count toward the lower bound. For a length that can't change, see `exact::Exact`.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn size_hint(&self) -> (usize, Option<usize>) {
+        let stride = self.stride.get();
+        let cached_len = self.cache.cached_len();
+        let cached = self.cached_ahead();
+        // Cursor positions (from `self.index`, one stride apart) in `cached_len..end`.
+        let uncached = |end: usize| {
+            let first = if self.index >= cached_len {
+                self.index
+            } else {
+                let behind = cached_len.wrapping_sub(self.index);
+                cached_len.saturating_add(stride.wrapping_sub(behind % stride) % stride)
+            };
+            end.checked_sub(first)
+                .and_then(|span| span.checked_sub(1))
+                .map_or(0, |span| span / stride + 1)
+        };
+        let (lower, upper) = self.cache.uncached_hint();
+        // Past the cache, a read can give up before the source does: on a `Probe` budget, or once `Overflow::Refuse` stops us.
+        let lower = if matches!(self.probe, cache::Probe::Exhaust) && !self.cache.may_refuse() {
+            lower
+        } else {
+            0
+        };
+        (
+            cached.saturating_add(uncached(cached_len.saturating_add(lower))),
+            upper.and_then(|upper| {
+                cached_len
+                    .checked_add(upper)
+                    .and_then(|end| cached.checked_add(uncached(end)))
+            }),
+        )
+    }
+
+    /// Number of cached elements `next` would still return (i.e. not evicted, and on the stride from the cursor).
+    #[cfg(feature = "unstable")]
+    #[inline]
+    fn cached_ahead(&self) -> usize {
+        let stride = self.stride.get();
+        self.cache
+            .cached_range(self.index..usize::MAX)
+            .filter(|indexed| indexed.index.wrapping_sub(self.index) % stride == 0)
+            .count()
+    }
+
+    /// Compute and cache every remaining element, returning the total number of elements (including any evicted).
+    /// Unlike draining an `Iterator`, everything stays seekable. Never returns if the source is infinite.
+    /// # Errors
+    /// If `Overflow::Refuse` stopped us before the source ended (everything computed until then stays cached).
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn exhaust(&mut self) -> Result<usize, cache::CacheFull> {
+        let len = self.cache.exhaust();
+        self.notify();
+        len
+    }
+
+    /// Like `Iterator::count`: how many elements `next` would still return, but computed by exhausting into the cache
+    /// rather than draining, and without moving the cursor. Never returns if the source is infinite.
+    /// # Errors
+    /// If `Overflow::Refuse` stopped us before the source ended.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn count(&mut self) -> Result<usize, cache::CacheFull> {
+        let _ = self.exhaust()?;
+        Ok(self.cached_ahead())
+    }
+
+    /// Return the element `from_end` places before the last (so zero means the last element), provided it's in bounds.
+    /// Finds the end by exhausting the source, unless its `size_hint` is exact (as for any `ExactSizeIterator`),
+    /// in which case we compute only up to the element you asked for. Doesn't move the cursor.
+    /// Never returns if the source is infinite. For double-ended sources, `at_back` computes nothing before the element either.
+    /// `None` as well if `Overflow::Refuse` stopped us before we found the end.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn at_from_end(&mut self, from_end: usize) -> Option<&I::Item> {
+        let len = match self.cache.len_without_computing() {
+            Some(len) => len,
+            None => self.exhaust().ok()?,
+        };
+        self.at(len.checked_sub(from_end)?.checked_sub(1)?)
+    }
+
+    /// Like `Iterator::last`: the final (non-evicted) element, computed by exhausting into the cache
+    /// rather than draining, and without moving the cursor. Never returns if the source is infinite.
+    /// # Errors
+    /// If `Overflow::Refuse` stopped us before the source ended.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn last(&mut self) -> Result<Option<indexed::Indexed<'_, I::Item>>, cache::CacheFull> {
+        let len = self.exhaust()?;
+        Ok(self.cache.cached_range(0..len).last())
     }
 
     /// Map `Indexed`s to a known lifetime.
@@ -201,6 +1007,8 @@ impl<I: Iterator> Reiterator<I> {
         Map {
             iter: self,
             un_reference_inator,
+            #[cfg(feature = "unstable")]
+            revert_to: None,
         }
     }
 
@@ -214,6 +1022,8 @@ impl<I: Iterator> Reiterator<I> {
         MapIndices {
             iter: self,
             un_reference_inator,
+            #[cfg(feature = "unstable")]
+            revert_to: None,
         }
     }
 
@@ -227,6 +1037,8 @@ impl<I: Iterator> Reiterator<I> {
         MapValues {
             iter: self,
             un_reference_inator,
+            #[cfg(feature = "unstable")]
+            revert_to: None,
         }
     }
 
@@ -242,10 +1054,193 @@ impl<I: Iterator> Reiterator<I> {
         Map {
             iter: self,
             un_reference_inator: |indexed| (indexed.index, indexed.value.clone()),
+            #[cfg(feature = "unstable")]
+            revert_to: None,
         }
     }
 
-    // TODO: fold, filter, ...
+    /// Clone values lazily as we produce them, keeping each one's index.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub fn indexed_owned(
+        self,
+    ) -> Map<
+        I,
+        impl FnMut(indexed::Indexed<'_, I::Item>) -> indexed::OwnedIndexed<I::Item>,
+        indexed::OwnedIndexed<I::Item>,
+    >
+    where
+        I::Item: Clone,
+    {
+        Map {
+            iter: self,
+            un_reference_inator: |indexed| indexed::OwnedIndexed::from(indexed),
+            #[cfg(feature = "unstable")]
+            revert_to: None,
+        }
+    }
+
+    /// Compute (if we haven't already) and clone every element in `range` into any collection, stopping early if the source ends.
+    /// Evicted elements are skipped. Doesn't move the cursor.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn collect_range<Collection: FromIterator<I::Item>>(
+        &mut self,
+        range: core::ops::Range<usize>,
+    ) -> Collection
+    where
+        I::Item: Clone,
+    {
+        self.force_until(range.end);
+        self.cache
+            .cached_range(range)
+            .map(indexed::clone_value)
+            .collect()
+    }
+
+    /// Like `collect_range`, but append into an existing collection (e.g. to reuse a `Vec`'s capacity).
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn collect_range_into<Collection: Extend<I::Item>>(
+        &mut self,
+        range: core::ops::Range<usize>,
+        collection: &mut Collection,
+    ) where
+        I::Item: Clone,
+    {
+        self.force_until(range.end);
+        collection.extend(self.cache.cached_range(range).map(indexed::clone_value));
+    }
+
+    /// Like `collect_all`, but append into an existing collection (e.g. to reuse a `Vec`'s capacity).
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    pub fn collect_all_into<Collection: Extend<I::Item>>(&mut self, collection: &mut Collection)
+    where
+        I::Item: Clone,
+    {
+        self.collect_range_into(0..usize::MAX, collection);
+    }
+
+    /// Compute (if we haven't already) every element before `end`, stopping early if the source ends.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    fn force_until(&mut self, end: usize) {
+        if let Some(last) = end.checked_sub(1) {
+            let _ = self.at(last);
+        }
+    }
+
+    /// Exhaust the source and clone every element into any collection.
+    /// Evicted elements are skipped. Doesn't move the cursor.
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    #[must_use]
+    pub fn collect_all<Collection: FromIterator<I::Item>>(&mut self) -> Collection
+    where
+        I::Item: Clone,
+    {
+        self.collect_range(0..usize::MAX)
+    }
+
+    /// Clone every element from the cursor up to (but not including) the first one matching `predicate`,
+    /// leaving the cursor on that first match (or past the end if nothing matched). Evicted elements are skipped.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn collect_until<Predicate: FnMut(&I::Item) -> bool>(
+        &mut self,
+        mut predicate: Predicate,
+    ) -> Vec<I::Item>
+    where
+        I::Item: Clone,
+    {
+        let mut collected = Vec::new();
+        loop {
+            if !self.cache.is_evicted(self.index) {
+                match self.at(self.index) {
+                    Some(item) if !predicate(item) => collected.push(item.clone()),
+                    _ => return collected,
+                }
+            }
+            if self.lazy_next().is_none() {
+                return collected;
+            }
+        }
+    }
+
+    /// Whether the whole sequence is exactly `expected`, computing at most `expected.len() + 1` elements to find out.
+    /// Evicted elements never compare equal. Doesn't move the cursor.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    #[must_use]
+    pub fn eq_slice<Expected>(&mut self, expected: &[Expected]) -> bool
+    where
+        I::Item: PartialEq<Expected>,
+    {
+        expected
+            .iter()
+            .enumerate()
+            .all(|(index, rhs)| self.at(index).is_some_and(|lhs| lhs == rhs))
+            && self.at(expected.len()).is_none()
+            && !self.cache.is_evicted(expected.len())
+    }
+
+    /// Like `Iterator::fold`, from the cursor to the end, walking cached elements by reference
+    /// and only computing new elements once the cache runs out. Leaves the cursor past the end.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn fold<
+        Accumulator,
+        Folder: FnMut(Accumulator, indexed::Indexed<'_, I::Item>) -> Accumulator,
+    >(
+        &mut self,
+        init: Accumulator,
+        mut folder: Folder,
+    ) -> Accumulator {
+        let mut accumulator = init;
+        while let Some(indexed) = self.next() {
+            accumulator = folder(accumulator, indexed);
+        }
+        accumulator
+    }
+
+    /// Like `Iterator::try_fold`, from the cursor, walking cached elements by reference
+    /// and only computing new elements once the cache runs out.
+    /// On error, leaves the cursor just past the element that caused it.
+    /// # Errors
+    /// The first error `folder` returns.
+    #[cfg(feature = "unstable")]
+    #[inline]
+    pub fn try_fold<
+        Accumulator,
+        Error,
+        Folder: FnMut(Accumulator, indexed::Indexed<'_, I::Item>) -> Result<Accumulator, Error>,
+    >(
+        &mut self,
+        init: Accumulator,
+        mut folder: Folder,
+    ) -> Result<Accumulator, Error> {
+        let mut accumulator = init;
+        while let Some(indexed) = self.next() {
+            accumulator = folder(accumulator, indexed)?;
+        }
+        Ok(accumulator)
+    }
+}
+
+/// Where a map adapter's `into_inner` leaves the cursor.
+#[cfg(feature = "unstable")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum CursorOnUnwrap {
+    /// Just past the last element consumed, as if you'd been calling `next` on the `Reiterator` yourself.
+    #[default]
+    Keep,
+    /// Back where it was when the adapter was set up, as if the adapter had been reading a copy.
+    Revert,
 }
 
 /// Map `Indexed`s to a known lifetime.
@@ -257,6 +1252,64 @@ pub struct Map<
 > {
     iter: Reiterator<I>,
     un_reference_inator: UnReferenceInator,
+    #[cfg(feature = "unstable")]
+    revert_to: Option<usize>,
+}
+
+#[cfg(feature = "unstable")]
+impl<I: Iterator, UnReferenceInator: FnMut(indexed::Indexed<'_, I::Item>) -> Output, Output>
+    Map<I, UnReferenceInator, Output>
+{
+    /// Map the element at `index` (computing it if we haven't), provided it's in bounds. Doesn't move the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<Output> {
+        self.iter
+            .at(index)
+            .map(|value| (self.un_reference_inator)(indexed::Indexed { index, value }))
+    }
+
+    /// Set the index to zero.
+    #[inline(always)]
+    pub fn restart(&mut self) {
+        self.iter.restart();
+    }
+
+    /// Index of the element `next` will produce.
+    #[inline(always)]
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.iter.index
+    }
+
+    /// Move the cursor anywhere, just like assigning `Reiterator::index`.
+    #[inline(always)]
+    pub fn set_index(&mut self, index: usize) {
+        self.iter.index = index;
+    }
+
+    /// Choose where `into_inner` leaves the cursor. Call this right after mapping: `Revert` remembers the cursor as of this call.
+    #[inline(always)]
+    #[must_use]
+    pub const fn on_unwrap(mut self, cursor: CursorOnUnwrap) -> Self {
+        self.revert_to = match cursor {
+            CursorOnUnwrap::Keep => None,
+            CursorOnUnwrap::Revert => Some(self.iter.index),
+        };
+        self
+    }
+
+    /// Return the unmapped `Reiterator`, with every element this adapter computed still cached.
+    /// The cursor is wherever `on_unwrap` said: by default, just past the last element consumed.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Reiterator<I> {
+        let mut iter = self.iter;
+        if let Some(index) = self.revert_to {
+            iter.index = index;
+        }
+        iter
+    }
 }
 
 impl<I: Iterator, UnReferenceInator: FnMut(indexed::Indexed<'_, I::Item>) -> Output, Output>
@@ -268,11 +1321,12 @@ impl<I: Iterator, UnReferenceInator: FnMut(indexed::Indexed<'_, I::Item>) -> Out
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(&mut self.un_reference_inator)
     }
-}
 
-impl<I: Iterator, UnReferenceInator: FnMut(indexed::Indexed<'_, I::Item>) -> Output, Output>
-    ExactSizeIterator for Map<I, UnReferenceInator, Output>
-{
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
 /// Map indices to a known lifetime.
@@ -280,6 +1334,64 @@ impl<I: Iterator, UnReferenceInator: FnMut(indexed::Indexed<'_, I::Item>) -> Out
 pub struct MapIndices<I: Iterator, UnReferenceInator: FnMut(usize) -> Output, Output> {
     iter: Reiterator<I>,
     un_reference_inator: UnReferenceInator,
+    #[cfg(feature = "unstable")]
+    revert_to: Option<usize>,
+}
+
+#[cfg(feature = "unstable")]
+impl<I: Iterator, UnReferenceInator: FnMut(usize) -> Output, Output>
+    MapIndices<I, UnReferenceInator, Output>
+{
+    /// Map `index` if it's in bounds (computing the element if we haven't). Doesn't move the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<Output> {
+        self.iter
+            .at(index)
+            .map(|_| (self.un_reference_inator)(index))
+    }
+
+    /// Set the index to zero.
+    #[inline(always)]
+    pub fn restart(&mut self) {
+        self.iter.restart();
+    }
+
+    /// Index of the element `next` will produce.
+    #[inline(always)]
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.iter.index
+    }
+
+    /// Move the cursor anywhere, just like assigning `Reiterator::index`.
+    #[inline(always)]
+    pub fn set_index(&mut self, index: usize) {
+        self.iter.index = index;
+    }
+
+    /// Choose where `into_inner` leaves the cursor. Call this right after mapping: `Revert` remembers the cursor as of this call.
+    #[inline(always)]
+    #[must_use]
+    pub const fn on_unwrap(mut self, cursor: CursorOnUnwrap) -> Self {
+        self.revert_to = match cursor {
+            CursorOnUnwrap::Keep => None,
+            CursorOnUnwrap::Revert => Some(self.iter.index),
+        };
+        self
+    }
+
+    /// Return the unmapped `Reiterator`, with every element this adapter computed still cached.
+    /// The cursor is wherever `on_unwrap` said: by default, just past the last element consumed.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Reiterator<I> {
+        let mut iter = self.iter;
+        if let Some(index) = self.revert_to {
+            iter.index = index;
+        }
+        iter
+    }
 }
 
 impl<I: Iterator, UnReferenceInator: FnMut(usize) -> Output, Output> Iterator
@@ -293,11 +1405,11 @@ impl<I: Iterator, UnReferenceInator: FnMut(usize) -> Output, Output> Iterator
             .next()
             .map(|indexed| (self.un_reference_inator)(indexed.index))
     }
-}
-
-impl<I: Iterator, UnReferenceInator: FnMut(usize) -> Output, Output> ExactSizeIterator
-    for MapIndices<I, UnReferenceInator, Output>
-{
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
 /// Map values to a known lifetime.
@@ -305,6 +1417,62 @@ impl<I: Iterator, UnReferenceInator: FnMut(usize) -> Output, Output> ExactSizeIt
 pub struct MapValues<I: Iterator, UnReferenceInator: FnMut(&I::Item) -> Output, Output> {
     iter: Reiterator<I>,
     un_reference_inator: UnReferenceInator,
+    #[cfg(feature = "unstable")]
+    revert_to: Option<usize>,
+}
+
+#[cfg(feature = "unstable")]
+impl<I: Iterator, UnReferenceInator: FnMut(&I::Item) -> Output, Output>
+    MapValues<I, UnReferenceInator, Output>
+{
+    /// Map the element at `index` (computing it if we haven't), provided it's in bounds. Doesn't move the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<Output> {
+        self.iter.at(index).map(&mut self.un_reference_inator)
+    }
+
+    /// Set the index to zero.
+    #[inline(always)]
+    pub fn restart(&mut self) {
+        self.iter.restart();
+    }
+
+    /// Index of the element `next` will produce.
+    #[inline(always)]
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.iter.index
+    }
+
+    /// Move the cursor anywhere, just like assigning `Reiterator::index`.
+    #[inline(always)]
+    pub fn set_index(&mut self, index: usize) {
+        self.iter.index = index;
+    }
+
+    /// Choose where `into_inner` leaves the cursor. Call this right after mapping: `Revert` remembers the cursor as of this call.
+    #[inline(always)]
+    #[must_use]
+    pub const fn on_unwrap(mut self, cursor: CursorOnUnwrap) -> Self {
+        self.revert_to = match cursor {
+            CursorOnUnwrap::Keep => None,
+            CursorOnUnwrap::Revert => Some(self.iter.index),
+        };
+        self
+    }
+
+    /// Return the unmapped `Reiterator`, with every element this adapter computed still cached.
+    /// The cursor is wherever `on_unwrap` said: by default, just past the last element consumed.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Reiterator<I> {
+        let mut iter = self.iter;
+        if let Some(index) = self.revert_to {
+            iter.index = index;
+        }
+        iter
+    }
 }
 
 impl<I: Iterator, UnReferenceInator: FnMut(&I::Item) -> Output, Output> Iterator
@@ -318,22 +1486,130 @@ impl<I: Iterator, UnReferenceInator: FnMut(&I::Item) -> Output, Output> Iterator
             .next()
             .map(|indexed| (self.un_reference_inator)(indexed.value))
     }
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
-impl<I: Iterator, UnReferenceInator: FnMut(&I::Item) -> Output, Output> ExactSizeIterator
-    for MapValues<I, UnReferenceInator, Output>
+/// Owning iterator over cloned values and their indices, starting from the cursor.
+#[cfg(feature = "unstable")]
+#[allow(missing_debug_implementations)]
+pub struct IntoIter<I: Iterator> {
+    /// Cache and cursor we're cloning out of.
+    iter: Reiterator<I>,
+}
+
+#[cfg(feature = "unstable")]
+impl<I: Iterator> Iterator for IntoIter<I>
+where
+    I::Item: Clone,
+{
+    type Item = (usize, I::Item);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|indexed| (indexed.index, indexed.value.clone()))
+    }
+
+    #[cfg(feature = "unstable")]
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl<I: Iterator> IntoIterator for Reiterator<I>
+where
+    I::Item: Clone,
+{
+    type Item = (usize, I::Item);
+    type IntoIter = IntoIter<I>;
+
+    /// Clone values lazily as we produce them, so a `Reiterator` can go anywhere an `Iterator` can (e.g. `for` loops).
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { iter: self }
+    }
+}
+
+/// Subscriptions aren't cloned: callbacks belong to the original, so a clone starts with none.
+#[cfg(feature = "unstable")]
+impl<I: Iterator + Clone> Clone for Reiterator<I>
+where
+    I::Item: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            cache: self.cache.clone(),
+            index: self.index,
+            back_index: self.back_index,
+            subscriptions: alloc::vec![],
+            probe: self.probe,
+            budgeted: self.budgeted,
+            stride: self.stride,
+            #[cfg(all(feature = "cursor-history", feature = "unstable"))]
+            cursor_history: self.cursor_history.clone(),
+            #[cfg(all(feature = "profiling", feature = "unstable"))]
+            access_profile: self.access_profile,
+        }
+    }
+
+    /// Reuses our cache's storage (and the box of every element we both have), so periodic copies stop reallocating.
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.copy_state_from(source);
+        self.subscriptions.clear();
+        self.pin_owed();
+        self.probe = source.probe;
+        self.stride = source.stride;
+        #[cfg(all(feature = "cursor-history", feature = "unstable"))]
+        self.cursor_history.clone_from(&source.cursor_history);
+        #[cfg(all(feature = "profiling", feature = "unstable"))]
+        {
+            self.access_profile = source.access_profile;
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl<I: Iterator + Clone> Reiterator<I>
+where
+    I::Item: Clone,
 {
+    /// Overwrite everything cached and both cursors with copies of `source`'s, reusing our storage like `clone_from`,
+    /// but keep our own settings and subscribers (who pick up from the new cache on the next read).
+    #[inline]
+    pub fn copy_state_from(&mut self, source: &Self) {
+        self.cache.clone_from(&source.cache);
+        self.index = source.index;
+        self.back_index = source.back_index;
+        self.budgeted = source.budgeted;
+        self.rewind_subscriptions(self.cache.cached_len());
+    }
+}
+
+/// Cache each item in turn ahead of anything the source has yet to produce. See `Reiterator::push_front_of_source`.
+/// Panics wherever that would hand an item back (i.e. under `Overflow::Refuse`), since there's nowhere to hand it.
+#[cfg(feature = "unstable")]
+impl<I: Iterator> Extend<I::Item> for Reiterator<I> {
+    #[inline]
+    fn extend<Items: IntoIterator<Item = I::Item>>(&mut self, items: Items) {
+        self.cache.extend(items);
+        self.notify();
+    }
 }
 
 /// Create a `Reiterator` from anything that can be turned into an `Iterator`.
 #[inline(always)]
 #[must_use]
 pub fn reiterate<I: IntoIterator>(iter: I) -> Reiterator<I::IntoIter> {
-    use cache::Cached;
-    Reiterator {
-        cache: iter.cached(),
-        index: 0,
-    }
+    Reiterator::new(iter)
 }
 
 /// Pipe the output of an `IntoIter` to make a `Reiterator`.
@@ -350,3 +1626,16 @@ impl<I: IntoIterator> Reiterate for I {
         reiterate(self)
     }
 }
+
+/// The committed, semver-guarded API: everything here is stable across minor versions.
+/// Everything else (including every other method on `Reiterator`) is experimental, lives behind the opt-in `unstable` feature,
+/// and may change at any time. Build without it to make sure you only depend on what's here:
+///   - `Reiterator`: `new`, `at`, `get`, `next`, `lazy_next`, `restart`, the `index` field, and the `map`/`map_indices`/`map_values`/`cloned` adapters
+///   - `Indexed` and its `index`/`value`/`clone_value`/`copy_value` helpers
+///   - `Reiterate` and `reiterate`
+pub mod stable {
+    pub use crate::{
+        indexed::{clone_value, copy_value, index, value, Indexed},
+        reiterate, Map, MapIndices, MapValues, Reiterate, Reiterator,
+    };
+}
@@ -0,0 +1,95 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Mapped view that caches its outputs too, so an expensive per-element transform runs at most once per element,
+//! no matter how many times you rewind. Compare `MapValues`, which reruns its closure on every pass.
+
+use crate::{indexed::Indexed, Reiterator};
+use ::alloc::vec::Vec;
+
+/// View of every element passed through a function, each output computed at most once.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct MemoMap<I: Iterator, Function: FnMut(&I::Item) -> Output, Output> {
+    /// Cached inputs.
+    iter: Reiterator<I>,
+
+    /// Run at most once per element.
+    function: Function,
+
+    /// Outputs computed so far, by index (`None` if we haven't needed that one yet).
+    mapped: Vec<Option<Output>>,
+
+    /// Safe to edit! See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<I: Iterator, Function: FnMut(&I::Item) -> Output, Output> MemoMap<I, Function, Output> {
+    /// Return the output at the requested index *or compute it (and its input) if we haven't*, provided it's in bounds.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&Output> {
+        if self.mapped.get(index).map_or(true, Option::is_none) {
+            let output = (self.function)(self.iter.at(index)?);
+            if self.mapped.len() <= index {
+                self.mapped.resize_with(index.checked_add(1)?, || None);
+            }
+            *self.mapped.get_mut(index)? = Some(output);
+        }
+        self.mapped.get(index)?.as_ref()
+    }
+
+    /// Return the current output, provided it's in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&mut self) -> Option<Indexed<'_, Output>> {
+        let index = self.index;
+        Some(Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+
+    /// Like `Iterator::next` but with a dependent lifetime.
+    #[inline]
+    pub fn next(&mut self) -> Option<Indexed<'_, Output>> {
+        let index = self.index;
+        self.index = index.checked_add(1)?;
+        Some(Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+
+    /// Set the index to zero. Never reruns the function.
+    #[inline(always)]
+    pub fn restart(&mut self) {
+        self.index = 0;
+    }
+
+    /// Return the unmapped `Reiterator`, with every input this view computed still cached (but the outputs dropped).
+    #[inline(always)]
+    #[must_use]
+    pub fn into_inner(self) -> Reiterator<I> {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// View of every element passed through `function`, caching each output so rewinding costs nothing.
+    #[inline(always)]
+    #[must_use]
+    pub const fn memo_map<Function: FnMut(&I::Item) -> Output, Output>(
+        self,
+        function: Function,
+    ) -> MemoMap<I, Function, Output> {
+        MemoMap {
+            iter: self,
+            function,
+            mapped: Vec::new(),
+            index: 0,
+        }
+    }
+}
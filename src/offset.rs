@@ -0,0 +1,106 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Translation between element indices and byte offsets for streams decoded from bytes (chars, records, tokens, ...).
+//! You say how many bytes each element took up; we keep a running table, built only as far as anyone asks,
+//! so an error position from a lower layer maps to an element (and back) without rescanning.
+
+use crate::Reiterator;
+use ::alloc::vec::Vec;
+use core::ops::Range;
+
+/// View that knows where in the underlying bytes each element came from.
+#[allow(missing_debug_implementations)]
+pub struct Offsets<I: Iterator, Measure: FnMut(&I::Item) -> usize> {
+    /// Cached elements.
+    iter: Reiterator<I>,
+
+    /// Bytes each element took up. Run at most once per element.
+    measure: Measure,
+
+    /// Byte offset just past each element measured so far; always a prefix of the whole sequence.
+    ends: Vec<usize>,
+}
+
+impl<I: Iterator, Measure: FnMut(&I::Item) -> usize> Offsets<I, Measure> {
+    /// Measure the next element (computing it if we haven't). Returns whether there was one we could measure.
+    #[inline]
+    fn measure_one(&mut self) -> bool {
+        let start = self.ends.last().copied().unwrap_or(0);
+        let Some(item) = self.iter.at(self.ends.len()) else {
+            return false;
+        };
+        self.ends.push(start.saturating_add((self.measure)(item)));
+        true
+    }
+
+    /// Return the element at the requested index *or compute it if we haven't*, provided it's in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&I::Item> {
+        self.iter.at(index)
+    }
+
+    /// Bytes the element at `index` came from, measuring (and computing) everything up to it if we haven't.
+    /// `None` if `index` is out of bounds, or if any element up to it was evicted before we could measure it.
+    #[inline]
+    #[must_use]
+    pub fn byte_range_of(&mut self, index: usize) -> Option<Range<usize>> {
+        while self.ends.len() <= index {
+            if !self.measure_one() {
+                return None;
+            }
+        }
+        let start = index
+            .checked_sub(1)
+            .map_or(Some(0), |before| self.ends.get(before).copied())?;
+        Some(start..*self.ends.get(index)?)
+    }
+
+    /// Byte offset where the element at `index` starts. See `byte_range_of`.
+    #[inline(always)]
+    #[must_use]
+    pub fn byte_offset_of(&mut self, index: usize) -> Option<usize> {
+        self.byte_range_of(index).map(|range| range.start)
+    }
+
+    /// Index of the element whose bytes include `offset`, measuring only as far as that byte.
+    /// Zero-length elements never contain anything. `None` if `offset` is past the last element.
+    #[inline]
+    #[must_use]
+    pub fn element_at_byte(&mut self, offset: usize) -> Option<usize> {
+        while self.ends.last().map_or(true, |&end| end <= offset) {
+            if !self.measure_one() {
+                return None;
+            }
+        }
+        Some(self.ends.partition_point(|&end| end <= offset))
+    }
+
+    /// Return the underlying `Reiterator`, with every element this view computed still cached (but the offset table dropped).
+    #[inline(always)]
+    #[must_use]
+    pub fn into_inner(self) -> Reiterator<I> {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// View that can translate between element indices and byte offsets, given how many bytes each element took up
+    /// (e.g. `|c| c.len_utf8()` for chars decoded from UTF-8).
+    #[inline(always)]
+    #[must_use]
+    pub const fn offsets<Measure: FnMut(&I::Item) -> usize>(
+        self,
+        measure: Measure,
+    ) -> Offsets<I, Measure> {
+        Offsets {
+            iter: self,
+            measure,
+            ends: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Hints about where you're about to read, to smooth out latency in pointer-chasing access patterns.
+//! Each cached element lives in its own allocation, so jumping between far-apart indices tends to miss the CPU cache;
+//! telling us ahead of time lets us compute what's missing and ask the CPU to start loading what isn't.
+
+use crate::{cache::Probe, Reiterator};
+
+/// Ask the CPU to start pulling the memory behind `ptr` into cache. No-op on targets without a prefetch instruction.
+#[inline(always)]
+#[cfg_attr(not(target_arch = "x86_64"), allow(unused_variables))]
+fn prefetch<T>(ptr: *const T) {
+    #[cfg(target_arch = "x86_64")]
+    // SAFETY: prefetching never faults, even on invalid addresses, and SSE is part of the x86_64 baseline.
+    #[allow(unsafe_code, unused_unsafe)]
+    unsafe {
+        core::arch::x86_64::_mm_prefetch::<{ core::arch::x86_64::_MM_HINT_T0 }>(ptr.cast());
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Get ready to read each of `indices` soon, in any order: compute any that aren't cached yet
+    /// (only as far as the current `Probe` policy allows, as if the furthest one were a cursor read),
+    /// then ask the CPU to prefetch every one that is. Doesn't move the cursor.
+    #[inline]
+    pub fn hint_next_access(&mut self, indices: &[usize]) {
+        if let Some(&furthest) = indices.iter().max() {
+            let end = furthest.saturating_add(1);
+            let end = match self.probe {
+                Probe::Exhaust => end,
+                Probe::AtMost(limit) => end.min(self.cached_len().saturating_add(limit)),
+                Probe::CacheOnly => 0,
+            };
+            self.force_until(end);
+        }
+        for &index in indices {
+            if let Some(item) = self.cache.cached(index) {
+                prefetch(item);
+            }
+        }
+    }
+}
@@ -0,0 +1,224 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Profiling aid: summarize how a real workload reads the cache, to pick growth, probe, and eviction settings from data instead of guesses.
+//! Every read that goes through `at` (which includes `get`, `next`, and most views) is counted.
+//! `AccessReport::suggest` turns the numbers into settings; `Reiterator::auto_tune` applies them for you after a warm-up.
+
+use crate::{cache::Growth, Reiterator};
+
+/// Running totals, updated on every read.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) struct AccessProfile {
+    /// Summary so far.
+    report: AccessReport,
+
+    /// Last index read.
+    last: Option<usize>,
+
+    /// Furthest index read.
+    furthest: Option<usize>,
+
+    /// Whether we're tuning ourselves, and how far along.
+    auto: AutoTune,
+
+    /// Every element before this has already been evicted by the tuned window.
+    evicted_below: usize,
+
+    /// Whether the tuned window is on hold (see `Reiterator::suspend_eviction`).
+    pub(crate) suspended: bool,
+}
+
+/// Where auto-tuning is.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum AutoTune {
+    /// Not tuning: just counting.
+    #[default]
+    Off,
+    /// Counting this many more reads before deciding.
+    Observing(usize),
+    /// Decided.
+    Locked(Tuning),
+}
+
+impl AccessProfile {
+    /// Count a read of `index`, when `cached` elements had already been computed.
+    #[inline]
+    pub(crate) fn record(&mut self, index: usize, cached: usize) {
+        let report = &mut self.report;
+        report.reads = report.reads.saturating_add(1);
+        if self.last.and_then(|last| last.checked_add(1)) == Some(index) {
+            report.sequential = report.sequential.saturating_add(1);
+        }
+        if index < cached {
+            report.rereads = report.rereads.saturating_add(1);
+        }
+        if let Some(furthest) = self.furthest {
+            report.max_lookbehind = report.max_lookbehind.max(furthest.saturating_sub(index));
+        }
+        self.last = Some(index);
+        let furthest = self.furthest.map_or(index, |furthest| furthest.max(index));
+        self.furthest = Some(furthest);
+        report.furthest = furthest;
+    }
+
+    /// Elements from `len` on are about to be recomputed, so they haven't been evicted yet.
+    #[inline]
+    pub(crate) fn rewind(&mut self, len: usize) {
+        self.evicted_below = self.evicted_below.min(len);
+    }
+}
+
+/// How a `Reiterator` has been read since profiling started.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct AccessReport {
+    /// Total number of reads.
+    pub reads: usize,
+    /// Reads of the index right after the previous read's.
+    pub sequential: usize,
+    /// Reads of an element that had already been computed (whether or not it had since been evicted).
+    pub rereads: usize,
+    /// Furthest any read reached back behind the furthest index read before it.
+    pub max_lookbehind: usize,
+    /// Furthest index read.
+    pub furthest: usize,
+}
+
+/// Settings suggested by an `AccessReport`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Tuning {
+    /// Keep only this many elements behind the furthest read, evicting anything older (`None` to keep everything).
+    pub eviction_window: Option<usize>,
+    /// How storage should grow.
+    pub growth: Growth,
+}
+
+impl AccessReport {
+    /// Fraction of reads that were sequential (`0.0` with no reads). Near `1.0`, a streaming setup (small `Probe`, aggressive eviction) fits.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss, clippy::as_conversions)]
+    pub fn sequential_ratio(&self) -> f64 {
+        if self.reads == 0 {
+            0.0
+        } else {
+            self.sequential as f64 / self.reads as f64
+        }
+    }
+
+    /// Fraction of reads served from the cache (`0.0` with no reads). Near `0.0`, you may not need a cache at all.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss, clippy::as_conversions)]
+    pub fn reread_ratio(&self) -> f64 {
+        if self.reads == 0 {
+            0.0
+        } else {
+            self.rereads as f64 / self.reads as f64
+        }
+    }
+}
+
+impl AccessReport {
+    /// Settings that would have served the reads so far.
+    /// If reads never reached far behind the frontier (at most a quarter of the way back), an eviction window covering
+    /// the furthest lookbehind (with as much slack again) keeps memory bounded, and storage grows a window at a time;
+    /// otherwise, everything is kept and storage grows however `Vec` does.
+    #[inline]
+    #[must_use]
+    pub fn suggest(&self) -> Tuning {
+        let window = self.max_lookbehind.saturating_add(1).saturating_mul(2);
+        if self.reads == 0 || self.max_lookbehind.saturating_mul(4) > self.furthest {
+            Tuning::default()
+        } else {
+            Tuning {
+                eviction_window: Some(window),
+                growth: Growth::Chunk(window),
+            }
+        }
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Watch the next `observe` reads with everything kept, then lock in `AccessReport::suggest`'s settings:
+    /// its growth policy, and its eviction window (if any), enforced on every read from then on.
+    /// Like any eviction, reads further back than the window then see `None`. Restarts the report.
+    #[inline]
+    pub fn auto_tune(&mut self, observe: usize) {
+        self.access_profile = AccessProfile {
+            auto: AutoTune::Observing(observe),
+            suspended: self.access_profile.suspended,
+            ..AccessProfile::default()
+        };
+    }
+
+    /// Settings `auto_tune` locked in, once it has.
+    #[inline]
+    #[must_use]
+    pub const fn tuning(&self) -> Option<Tuning> {
+        match self.access_profile.auto {
+            AutoTune::Locked(tuning) => Some(tuning),
+            AutoTune::Off | AutoTune::Observing(_) => None,
+        }
+    }
+
+    /// After a read has been recorded (but before it's served, so we never evict what it returns): lock in settings if we've seen enough, then enforce the eviction window.
+    #[inline]
+    pub(crate) fn apply_tuning(&mut self) {
+        let profile = &mut self.access_profile;
+        match profile.auto {
+            AutoTune::Off => return,
+            AutoTune::Observing(remaining) => {
+                if let Some(decrement) = remaining.checked_sub(1) {
+                    profile.auto = AutoTune::Observing(decrement);
+                    return;
+                }
+                let tuning = profile.report.suggest();
+                profile.auto = AutoTune::Locked(tuning);
+                self.cache.set_growth(tuning.growth);
+            }
+            AutoTune::Locked(_) => {}
+        }
+        let AutoTune::Locked(Tuning {
+            eviction_window: Some(window),
+            ..
+        }) = self.access_profile.auto
+        else {
+            return;
+        };
+        if self.access_profile.suspended {
+            return;
+        }
+        // Only what's already computed: anything else gets its turn on a later read.
+        let keep_from = self
+            .access_profile
+            .report
+            .furthest
+            .saturating_sub(window)
+            .min(self.cache.cached_len());
+        for index in self.access_profile.evicted_below..keep_from {
+            self.cache.evict(index);
+        }
+        self.access_profile.evicted_below = self.access_profile.evicted_below.max(keep_from);
+    }
+
+    /// How this `Reiterator` has been read since it was created (or since `reset_access_report`).
+    #[inline(always)]
+    #[must_use]
+    pub const fn access_report(&self) -> AccessReport {
+        self.access_profile.report
+    }
+
+    /// Start counting from scratch, e.g. after a warm-up phase you don't want in the numbers. Leaves auto-tuning alone.
+    #[inline(always)]
+    pub fn reset_access_report(&mut self) {
+        self.access_profile.report = AccessReport::default();
+        self.access_profile.last = None;
+        self.access_profile.furthest = None;
+    }
+}
@@ -0,0 +1,88 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Reiterator that stores each element behind an `Rc` and hands out clones of that `Rc`.
+//! Values outlive any borrow of the reiterator, so you can keep them around (e.g. AST nodes pointing at cached tokens) without unsafe or lifetime contortions.
+
+use ::alloc::{rc::Rc, vec, vec::Vec};
+
+/// Caching repeatable iterator handing out shared ownership of each element.
+/// Since nothing it returns borrows `self`, this is also a real `Iterator`, yielding `(index, Rc<value>)`.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct RcReiterator<I: Iterator> {
+    /// Iterator producing the input being cached.
+    iter: I,
+
+    /// Elements we've already pulled out of `iter`.
+    vec: Vec<Rc<I::Item>>,
+
+    /// Safe to edit! See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<I: Iterator> RcReiterator<I> {
+    /// Set up the iterator to return the first element, but don't calculate it yet.
+    #[inline(always)]
+    pub fn new<II: IntoIterator<IntoIter = I>>(into_iter: II) -> Self {
+        Self {
+            iter: into_iter.into_iter(),
+            vec: vec![],
+            index: 0,
+        }
+    }
+
+    /// Set the index to zero.
+    #[inline(always)]
+    pub fn restart(&mut self) {
+        self.index = 0;
+    }
+
+    /// Return a new handle to the element at the requested index *or compute it if we haven't*, provided it's in bounds.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<Rc<I::Item>> {
+        loop {
+            if let Some(cached) = self.vec.get(index) {
+                return Some(Rc::clone(cached));
+            }
+            self.vec.push(Rc::new(self.iter.next()?));
+        }
+    }
+
+    /// Return the current element or compute it if we haven't, provided it's in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&mut self) -> Option<(usize, Rc<I::Item>)> {
+        Some((self.index, self.at(self.index)?))
+    }
+
+    /// Advance the index without computing the corresponding value.
+    #[inline(always)]
+    pub fn lazy_next(&mut self) -> Option<usize> {
+        self.index.checked_add(1).map(|incr| {
+            self.index = incr;
+            incr
+        })
+    }
+}
+
+impl<I: Iterator> Iterator for RcReiterator<I> {
+    type Item = (usize, Rc<I::Item>);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index;
+        let _ = self.lazy_next()?;
+        self.at(index).map(|value| (index, value))
+    }
+}
+
+/// Create an `RcReiterator` from anything that can be turned into an `Iterator`.
+#[inline(always)]
+#[must_use]
+pub fn reiterate_rc<I: IntoIterator>(iter: I) -> RcReiterator<I::IntoIter> {
+    RcReiterator::new(iter)
+}
@@ -0,0 +1,66 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Lend a `Reiterator` out as a plain `Iterator`, like `Iterator::by_ref`, and get it back with the cursor wherever the borrower left it.
+//! Nothing is evicted while it's lent out (`Overflow::Evict` acts like `Overflow::Refuse`, and windows stop sliding), since values handed out must stay put.
+
+use crate::{indexed::Indexed, Reiterator, Suspended};
+
+/// `Iterator` driving a borrowed `Reiterator`'s cursor.
+/// Values borrow the `Reiterator` itself, not this adapter, so they outlive each call to `next`.
+#[allow(missing_debug_implementations)]
+pub struct RefReiter<'iter, I: Iterator> {
+    /// Borrowed for as long as any value we've handed out.
+    iter: &'iter mut Reiterator<I>,
+
+    /// Eviction settings to put back once we're dropped.
+    suspended: Suspended,
+}
+
+impl<'iter, I: Iterator> Iterator for RefReiter<'iter, I> {
+    type Item = Indexed<'iter, I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let indexed = self.iter.next()?;
+        let index = indexed.index;
+        let pointer: *const I::Item = indexed.value;
+        Some(Indexed {
+            index,
+            #[allow(unsafe_code)]
+            // SAFETY: Elements are boxed, and nothing can evict them while `self.iter` stays mutably borrowed for `'iter`:
+            // we only ever compute (never drop) elements through it, and eviction is suspended until we're dropped.
+            // Even then, nothing is evicted until the `Reiterator` is used again, which the borrow checker forbids while any value is live.
+            value: unsafe { &*pointer },
+        })
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: Iterator> Drop for RefReiter<'_, I> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.iter.resume_eviction(self.suspended);
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Borrow this `Reiterator` as a plain `Iterator` (e.g. to hand to a function that wants one),
+    /// advancing this cursor as it goes. Nothing is evicted until the borrow ends (see the module docs).
+    #[inline]
+    #[must_use]
+    pub fn by_ref(&mut self) -> RefReiter<'_, I> {
+        let suspended = self.suspend_eviction();
+        RefReiter {
+            iter: self,
+            suspended,
+        }
+    }
+}
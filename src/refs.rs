@@ -0,0 +1,94 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Reiterator specialized for iterators over references (`Iterator<Item = &T>`).
+//! References are `Copy`, so we store them directly instead of boxing each one, and we hand out `&T` instead of `&&T`.
+
+use crate::indexed::Indexed;
+use ::alloc::{vec, vec::Vec};
+
+/// Caching repeatable iterator over references.
+/// Since every reference outlives `self`, this is also a real `Iterator`.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct RefReiterator<'item, Item, I: Iterator<Item = &'item Item>> {
+    /// Iterator producing the references being cached.
+    iter: I,
+
+    /// References we've already pulled out of `iter`.
+    vec: Vec<&'item Item>,
+
+    /// Safe to edit! See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<'item, Item, I: Iterator<Item = &'item Item>> RefReiterator<'item, Item, I> {
+    /// Set up the iterator to return the first element, but don't calculate it yet.
+    #[inline(always)]
+    pub fn new<II: IntoIterator<IntoIter = I>>(into_iter: II) -> Self {
+        Self {
+            iter: into_iter.into_iter(),
+            vec: vec![],
+            index: 0,
+        }
+    }
+
+    /// Set the index to zero.
+    #[inline(always)]
+    pub fn restart(&mut self) {
+        self.index = 0;
+    }
+
+    /// Return the reference at the requested index *or compute it if we haven't*, provided it's in bounds.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&'item Item> {
+        loop {
+            if let Some(&cached) = self.vec.get(index) {
+                return Some(cached);
+            }
+            self.vec.push(self.iter.next()?);
+        }
+    }
+
+    /// Return the current element or compute it if we haven't, provided it's in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&mut self) -> Option<Indexed<'item, Item>> {
+        Some(Indexed {
+            index: self.index,
+            value: self.at(self.index)?,
+        })
+    }
+
+    /// Advance the index without computing the corresponding value.
+    #[inline(always)]
+    pub fn lazy_next(&mut self) -> Option<usize> {
+        self.index.checked_add(1).map(|incr| {
+            self.index = incr;
+            incr
+        })
+    }
+}
+
+impl<'item, Item, I: Iterator<Item = &'item Item>> Iterator for RefReiterator<'item, Item, I> {
+    type Item = Indexed<'item, Item>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index;
+        let _ = self.lazy_next()?;
+        self.at(index).map(|value| Indexed { index, value })
+    }
+}
+
+/// Create a `RefReiterator` from anything that can be turned into an `Iterator` over references.
+#[inline(always)]
+#[must_use]
+pub fn reiterate_refs<'item, Item: 'item, I: IntoIterator<Item = &'item Item>>(
+    iter: I,
+) -> RefReiterator<'item, Item, I::IntoIter> {
+    RefReiterator::new(iter)
+}
@@ -0,0 +1,129 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Record every call the cache makes to its source, then replay it later, bit for bit.
+//! Meant for debugging accidentally impure iterators: record the run that went wrong, save the trace, and replay it as many times as you like.
+
+use crate::Reiterator;
+use ::alloc::{vec, vec::Vec};
+
+/// Ordered log of every value a source produced.
+/// Serializes to (and parses from) one line per miss via `Display` and `parse`.
+/// The `n`th miss is the `n`th value the source produced: that's its index in the cache only as long as nothing else
+/// fills the cache (e.g. `Reiterator::push_front_of_source` or seeded elements), so replaying goes by order alone.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Trace<Item> {
+    /// Every value the source produced, in order.
+    pub misses: Vec<Item>,
+}
+
+/// Error parsing a `Trace` from text.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct ParseError {
+    /// Zero-indexed line that couldn't be parsed.
+    pub line: usize,
+}
+
+impl<Item: core::str::FromStr> Trace<Item> {
+    /// Parse a trace written by `Display`.
+    /// # Errors
+    /// If any line isn't a value `Item::from_str` accepts.
+    #[inline]
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let misses = text
+            .lines()
+            .enumerate()
+            .map(|(line, entry)| entry.parse().map_err(|_| ParseError { line }))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { misses })
+    }
+}
+
+impl<Item: core::fmt::Display> core::fmt::Display for Trace<Item> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for miss in &self.misses {
+            writeln!(f, "{miss}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Source wrapper that clones every value it produces into a `Trace`.
+#[derive(Clone, Debug)]
+pub struct Recorder<I: Iterator> {
+    /// Source being recorded.
+    iter: I,
+
+    /// Everything `iter` has produced so far.
+    trace: Trace<I::Item>,
+}
+
+impl<I: Iterator> Iterator for Recorder<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.iter.next()?;
+        self.trace.misses.push(value.clone());
+        Some(value)
+    }
+}
+
+/// Source that produces exactly the values in a recorded `Trace`, in order.
+#[derive(Clone, Debug)]
+pub struct Replay<Item> {
+    /// Misses not yet replayed.
+    misses: vec::IntoIter<Item>,
+}
+
+impl<Item> Iterator for Replay<Item> {
+    type Item = Item;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.misses.next()
+    }
+}
+
+impl<I: Iterator> Reiterator<Recorder<I>>
+where
+    I::Item: Clone,
+{
+    /// Everything the source has produced so far, in order.
+    #[inline(always)]
+    #[must_use]
+    pub fn trace(&self) -> &Trace<I::Item> {
+        &self.cache.source().trace
+    }
+}
+
+/// Create a `Reiterator` that logs every value its source produces.
+#[inline(always)]
+#[must_use]
+pub fn record<I: IntoIterator>(iter: I) -> Reiterator<Recorder<I::IntoIter>>
+where
+    I::Item: Clone,
+{
+    Reiterator::new(Recorder {
+        iter: iter.into_iter(),
+        trace: Trace { misses: vec![] },
+    })
+}
+
+/// Create a `Reiterator` whose source reproduces a recorded `Trace` exactly.
+#[inline(always)]
+#[must_use]
+pub fn replay<Item>(trace: Trace<Item>) -> Reiterator<Replay<Item>> {
+    Reiterator::new(Replay {
+        misses: trace.misses.into_iter(),
+    })
+}
@@ -0,0 +1,107 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Random samples of cached sequences, deterministic for a given seeded RNG.
+//! The source is sequential, so reaching a sampled index always computes everything before it;
+//! what differs is what we keep afterward.
+
+use crate::{indexed::Indexed, Reiterator};
+use ::alloc::vec::Vec;
+
+/// What to keep cached after sampling.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum SampleMode {
+    /// Keep every element we computed along the way.
+    #[default]
+    Dense,
+    /// Evict every element in the sampled range except the sample itself.
+    Sparse,
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Pick `k` distinct indices uniformly at random from the first `population` elements (or fewer, if the source ends first),
+    /// then return those elements in index order. Evicted elements in range are never picked. Doesn't move the cursor.
+    #[inline]
+    pub fn sample_indices<Rng: rand::Rng + ?Sized>(
+        &mut self,
+        rng: &mut Rng,
+        population: usize,
+        k: usize,
+        mode: SampleMode,
+    ) -> Vec<Indexed<'_, I::Item>> {
+        self.force_until(population);
+        let candidates: Vec<usize> = self
+            .cache
+            .cached_range(0..population)
+            .map(|indexed| indexed.index)
+            .collect();
+        let mut picked: Vec<usize> =
+            rand::seq::index::sample(rng, candidates.len(), k.min(candidates.len()))
+                .into_iter()
+                .filter_map(|position| candidates.get(position).copied())
+                .collect();
+        picked.sort_unstable();
+        if mode == SampleMode::Sparse {
+            let mut keep = picked.iter().peekable();
+            for &index in &candidates {
+                if keep.next_if_eq(&&index).is_none() {
+                    self.cache.evict(index);
+                }
+            }
+        }
+        picked
+            .into_iter()
+            .filter_map(|index| {
+                self.cache
+                    .cached(index)
+                    .map(|value| Indexed { index, value })
+            })
+            .collect()
+    }
+    /// Reservoir-sample `k` elements uniformly at random from the first `population` elements (or fewer, if the source ends first),
+    /// streaming through them one at a time and evicting every element not (or no longer) in the reservoir,
+    /// so at most `k` elements stay cached no matter how long the stream is. Evicted elements in range are never picked.
+    /// Returns the sample in index order. Doesn't move the cursor.
+    #[inline]
+    pub fn reservoir_sample<Rng: rand::Rng + ?Sized>(
+        &mut self,
+        k: usize,
+        rng: &mut Rng,
+        population: usize,
+    ) -> Vec<Indexed<'_, I::Item>> {
+        let mut reservoir: Vec<usize> = Vec::with_capacity(k);
+        let mut seen = 0_usize;
+        for index in 0..population {
+            if self.cache.is_evicted(index) {
+                continue;
+            }
+            if self.cache.get(index).is_none() {
+                break;
+            }
+            seen = seen.saturating_add(1);
+            if reservoir.len() < k {
+                reservoir.push(index);
+                continue;
+            }
+            let slot = rng.random_range(0..seen);
+            match reservoir.get_mut(slot) {
+                Some(displaced) => self.cache.evict(core::mem::replace(displaced, index)),
+                None => self.cache.evict(index),
+            }
+        }
+        self.notify();
+        reservoir.sort_unstable();
+        reservoir
+            .into_iter()
+            .filter_map(|index| {
+                self.cache
+                    .cached(index)
+                    .map(|value| Indexed { index, value })
+            })
+            .collect()
+    }
+}
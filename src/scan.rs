@@ -0,0 +1,157 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Rewindable `scan`: like `Iterator::scan`, but the accumulator is snapshotted at every index,
+//! so seeking back to any element sees exactly the state it saw the first time, and nothing is ever recomputed.
+
+use crate::{indexed::Indexed, Reiterator};
+use ::alloc::{vec, vec::Vec};
+
+/// View of every element passed through a stateful function, with the state before each element kept around.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct Scan<
+    I: Iterator,
+    State: Clone,
+    Function: FnMut(&mut State, &I::Item) -> Option<Output>,
+    Output,
+> {
+    /// Cached inputs.
+    iter: Reiterator<I>,
+
+    /// Run at most once per element.
+    function: Function,
+
+    /// State before each element scanned so far, plus the state after the last one.
+    states: Vec<State>,
+
+    /// Outputs so far, by index.
+    outputs: Vec<Output>,
+
+    /// Whether `function` said to stop (or the source ended).
+    done: bool,
+
+    /// Safe to edit! See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<
+        I: Iterator,
+        State: Clone,
+        Function: FnMut(&mut State, &I::Item) -> Option<Output>,
+        Output,
+    > Scan<I, State, Function, Output>
+{
+    /// Scan one more element. Returns whether it produced an output.
+    /// An evicted element can't be scanned, so we stop there (without giving up for good).
+    #[inline]
+    fn step(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        let index = self.outputs.len();
+        if self.iter.cache.is_evicted(index) {
+            return false;
+        }
+        let Some(mut state) = self.states.last().cloned() else {
+            return false;
+        };
+        let output = match self.iter.at(index) {
+            Some(item) => (self.function)(&mut state, item),
+            None => None,
+        };
+        match output {
+            Some(output) => {
+                self.outputs.push(output);
+                self.states.push(state);
+                true
+            }
+            None => {
+                self.done = true;
+                false
+            }
+        }
+    }
+
+    /// Return the output at the requested index *or scan up to it if we haven't*, provided it's in bounds.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&Output> {
+        while self.outputs.len() <= index {
+            if !self.step() {
+                return None;
+            }
+        }
+        self.outputs.get(index)
+    }
+
+    /// State just before the element at `index` was scanned (or, for one past the last, after everything),
+    /// scanning up to it if we haven't.
+    #[inline]
+    pub fn state_at(&mut self, index: usize) -> Option<&State> {
+        while self.states.len() <= index {
+            if !self.step() {
+                return None;
+            }
+        }
+        self.states.get(index)
+    }
+
+    /// Return the current output, provided it's in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&mut self) -> Option<Indexed<'_, Output>> {
+        let index = self.index;
+        Some(Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+
+    /// Like `Iterator::next` but with a dependent lifetime.
+    #[inline]
+    pub fn next(&mut self) -> Option<Indexed<'_, Output>> {
+        let index = self.index;
+        self.index = index.checked_add(1)?;
+        Some(Indexed {
+            index,
+            value: self.at(index)?,
+        })
+    }
+
+    /// Set the index to zero. Never reruns the function.
+    #[inline(always)]
+    pub fn restart(&mut self) {
+        self.index = 0;
+    }
+
+    /// Return the unscanned `Reiterator`, with every input this view computed still cached (but the outputs and states dropped).
+    #[inline(always)]
+    #[must_use]
+    pub fn into_inner(self) -> Reiterator<I> {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Like `Iterator::scan`, but rewindable: the state before each element is kept, so revisiting an element never reruns `function`.
+    /// Stops at the first element for which `function` returns `None`.
+    #[inline(always)]
+    #[must_use]
+    pub fn scan<State: Clone, Function: FnMut(&mut State, &I::Item) -> Option<Output>, Output>(
+        self,
+        initial: State,
+        function: Function,
+    ) -> Scan<I, State, Function, Output> {
+        Scan {
+            iter: self,
+            function,
+            states: vec![initial],
+            outputs: Vec::new(),
+            done: false,
+            index: 0,
+        }
+    }
+}
@@ -0,0 +1,269 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Several threads reading one cache at once, each with its own cursor, inside a scope that ends before the `Reiterator` is usable again.
+//! Computing new elements takes a lock; the references handed out don't, since boxed elements never move and nothing can evict them mid-scope
+//! (`Overflow::Evict` acts like `Overflow::Refuse`, and any `set_window` is suspended, until the scope ends).
+//! `WorkStealing` hands out index ranges so uneven per-element costs don't leave some workers idle while others are buried.
+
+use crate::{indexed::Indexed, Reiterator};
+use ::alloc::vec::Vec;
+use core::{num::NonZeroUsize, ops::Range};
+use std::{
+    panic,
+    sync::{Mutex, MutexGuard, PoisonError},
+    thread,
+};
+
+/// Lock, ignoring poison: every critical section here leaves its data consistent.
+#[inline(always)]
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Coordinator handing out chunks of an index range to workers, who steal from each other once their own share runs out.
+/// Each worker starts with an equal, contiguous share and claims it a chunk at a time from the front;
+/// a worker with nothing left takes the back half of whichever share has the most left.
+/// Every index is claimed exactly once.
+#[derive(Debug)]
+pub struct WorkStealing {
+    /// What's left of each worker's share.
+    shares: Vec<Mutex<Range<usize>>>,
+
+    /// Most indices handed out per claim.
+    chunk: NonZeroUsize,
+}
+
+impl WorkStealing {
+    /// Split `range` evenly among `workers`, to be claimed at most `chunk` indices at a time.
+    #[inline]
+    #[must_use]
+    pub fn new(range: Range<usize>, workers: usize, chunk: NonZeroUsize) -> Self {
+        let len = range.len();
+        // The first `len % workers` workers get one extra index each. Never overflows, unlike `len * worker / workers`.
+        let boundary = |worker: usize| {
+            range
+                .start
+                .wrapping_add((len / workers).wrapping_mul(worker))
+                .wrapping_add(worker.min(len % workers))
+        };
+        let shares = (0..workers)
+            .map(|worker| Mutex::new(boundary(worker)..boundary(worker.wrapping_add(1))))
+            .collect();
+        Self { shares, chunk }
+    }
+
+    /// Next chunk for `worker` to process, stealing if its own share is used up. `None` once nothing is left to claim.
+    #[inline]
+    pub fn claim(&self, worker: usize) -> Option<Range<usize>> {
+        let own = self.shares.get(worker)?;
+        loop {
+            {
+                let mut share = lock(own);
+                if !share.is_empty() {
+                    let end = share.end.min(share.start.saturating_add(self.chunk.get()));
+                    let claimed = share.start..end;
+                    share.start = end;
+                    return Some(claimed);
+                }
+            }
+            let (victim, left) = self
+                .shares
+                .iter()
+                .map(|share| (share, lock(share).len()))
+                .max_by_key(|&(_, left)| left)?;
+            if left == 0 {
+                return None; // Everything has been claimed (or is about to be processed by a thief).
+            }
+            let stolen = {
+                let mut share = lock(victim);
+                let take = share.len().div_ceil(2);
+                if take == 0 {
+                    continue; // Another thief emptied it since we looked, but someone else might still have work.
+                }
+                let start = share.end.wrapping_sub(take);
+                let stolen = start..share.end;
+                share.end = start;
+                stolen
+            };
+            *lock(own) = stolen;
+        }
+    }
+}
+
+/// One worker's cursor onto a cache shared with the others.
+/// Elements it returns live as long as the scope, so this is an ordinary `Iterator`.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct SharedCursor<'scope, I: Iterator> {
+    /// Shared by every worker in the scope.
+    shared: &'scope Mutex<&'scope mut Reiterator<I>>,
+
+    /// Which worker this is.
+    worker: usize,
+
+    /// Where to claim ranges from, if anywhere.
+    work: Option<&'scope WorkStealing>,
+
+    /// Safe to edit! See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<'scope, I: Iterator> SharedCursor<'scope, I> {
+    /// Which worker this is, from zero up to (but not including) the number of workers.
+    #[inline(always)]
+    #[must_use]
+    pub const fn worker(&self) -> usize {
+        self.worker
+    }
+
+    /// Next range of indices for this worker to process, if this scope is coordinated by `WorkStealing` (see `Reiterator::scope_work_stealing`).
+    /// `None` once everything has been claimed, or if there's no coordinator.
+    #[inline(always)]
+    #[must_use]
+    pub fn claim(&self) -> Option<Range<usize>> {
+        self.work?.claim(self.worker)
+    }
+
+    /// Return the element at the requested index *or compute it if no one has*, provided it's in bounds.
+    /// Always computes as far as it needs to, whatever the `Probe` policy, and never records reads for profiling. Doesn't move the cursor.
+    #[inline]
+    #[must_use]
+    pub fn at(&self, index: usize) -> Option<&'scope I::Item> {
+        let mut iter = lock(self.shared);
+        let pointer = iter.cache.get(index).map(|item| -> *const I::Item { item });
+        iter.notify();
+        drop(iter);
+        pointer.map(|pointer| {
+            #[allow(unsafe_code)]
+            // SAFETY: Elements are boxed, and the `Reiterator` stays mutably borrowed until the scope ends,
+            // so nothing can move, evict, or drop one before then. Cursors only ever add elements.
+            unsafe {
+                &*pointer
+            }
+        })
+    }
+
+    /// Return the current element, provided it's in bounds.
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&self) -> Option<Indexed<'scope, I::Item>> {
+        Some(Indexed {
+            index: self.index,
+            value: self.at(self.index)?,
+        })
+    }
+
+    /// Set the index to zero.
+    #[inline(always)]
+    pub fn restart(&mut self) {
+        self.index = 0;
+    }
+}
+
+impl<'scope, I: Iterator> Iterator for SharedCursor<'scope, I> {
+    type Item = Indexed<'scope, I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let indexed = self.get()?;
+        self.index = self.index.checked_add(1)?;
+        Some(indexed)
+    }
+}
+
+impl<I: Iterator + Send> Reiterator<I>
+where
+    I::Item: Send + Sync,
+{
+    /// Run `consumer` on `workers` threads at once, each with its own cursor (starting at zero) onto this one cache,
+    /// and return what each returned, in worker order. Every element is still computed at most once, by whichever worker gets there first.
+    /// Doesn't move this `Reiterator`'s cursor.
+    /// # Panics
+    /// If any worker panics (after every worker has finished).
+    #[inline]
+    pub fn scope_consumers<
+        Output: Send,
+        Consumer: for<'scope> Fn(SharedCursor<'scope, I>) -> Output + Sync,
+    >(
+        &mut self,
+        workers: usize,
+        consumer: Consumer,
+    ) -> Vec<Output> {
+        self.scope(workers, None, consumer)
+    }
+
+    /// Like `scope_consumers`, but with `range` split among the workers by `WorkStealing`, to be claimed with `SharedCursor::claim`.
+    /// # Panics
+    /// If any worker panics (after every worker has finished).
+    #[inline]
+    pub fn scope_work_stealing<
+        Output: Send,
+        Consumer: for<'scope> Fn(SharedCursor<'scope, I>) -> Output + Sync,
+    >(
+        &mut self,
+        range: Range<usize>,
+        workers: usize,
+        chunk: NonZeroUsize,
+        consumer: Consumer,
+    ) -> Vec<Output> {
+        let work = WorkStealing::new(range, workers, chunk);
+        self.scope(workers, Some(&work), consumer)
+    }
+
+    /// Run `consumer` on `workers` scoped threads, optionally coordinated by `work`, making sure nothing gets evicted in the meantime.
+    #[inline]
+    fn scope<Output: Send, Consumer: for<'scope> Fn(SharedCursor<'scope, I>) -> Output + Sync>(
+        &mut self,
+        workers: usize,
+        work: Option<&WorkStealing>,
+        consumer: Consumer,
+    ) -> Vec<Output> {
+        let suspended = self.suspend_eviction();
+        let outputs = self.scope_unchanged(workers, work, consumer);
+        self.resume_eviction(suspended);
+        // Workers can't call subscribers from other threads, so they're caught up here.
+        self.notify();
+        outputs
+    }
+
+    /// Run `consumer` on `workers` scoped threads, optionally coordinated by `work`, with nothing able to evict anything.
+    #[inline]
+    fn scope_unchanged<
+        Output: Send,
+        Consumer: for<'scope> Fn(SharedCursor<'scope, I>) -> Output + Sync,
+    >(
+        &mut self,
+        workers: usize,
+        work: Option<&WorkStealing>,
+        consumer: Consumer,
+    ) -> Vec<Output> {
+        let shared = Mutex::new(self);
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|worker| {
+                    let shared = &shared;
+                    let consumer = &consumer;
+                    scope.spawn(move || {
+                        consumer(SharedCursor {
+                            shared,
+                            worker,
+                            work,
+                            index: 0,
+                        })
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|payload| panic::resume_unwind(payload))
+                })
+                .collect()
+        })
+    }
+}
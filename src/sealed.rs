@@ -0,0 +1,94 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Encapsulated `Reiterator` whose cursor moves only through methods, so every movement goes through a checked path.
+//! Everything read-only is still available through `Deref`.
+
+use crate::{indexed::Indexed, Reiterator};
+
+/// `Reiterator` without a public `index` field. Seeking validates the destination before moving.
+/// There's deliberately no way back to a plain `Reiterator`.
+#[allow(missing_debug_implementations)]
+pub struct SealedReiterator<I: Iterator> {
+    /// Never handed out mutably.
+    iter: Reiterator<I>,
+}
+
+impl<I: Iterator> SealedReiterator<I> {
+    /// Return the element at the requested index *or compute it if we haven't*, provided it's in bounds. Doesn't move the cursor.
+    #[inline(always)]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&I::Item> {
+        self.iter.at(index)
+    }
+
+    /// See `Reiterator::get`.
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&mut self) -> Option<Indexed<'_, I::Item>> {
+        self.iter.get()
+    }
+
+    /// See `Reiterator::next`.
+    #[inline(always)]
+    pub fn next(&mut self) -> Option<Indexed<'_, I::Item>> {
+        self.iter.next()
+    }
+
+    /// See `Reiterator::prev`.
+    #[inline(always)]
+    pub fn prev(&mut self) -> Option<Indexed<'_, I::Item>> {
+        self.iter.prev()
+    }
+
+    /// See `Reiterator::advance_by`.
+    /// # Errors
+    /// Number of steps not taken if the source ended first.
+    #[inline(always)]
+    pub fn advance_by(&mut self, n: usize) -> Result<(), core::num::NonZeroUsize> {
+        self.iter.advance_by(n)
+    }
+
+    /// Set the cursor to zero.
+    #[inline(always)]
+    pub fn restart(&mut self) {
+        self.iter.restart();
+    }
+
+    /// Move the cursor to `index`, computing up to it if we haven't, but only if there's a (non-evicted) element there.
+    /// Otherwise, returns `None` without moving.
+    #[inline]
+    pub fn seek(&mut self, index: usize) -> Option<Indexed<'_, I::Item>> {
+        let _ = self.iter.at(index)?;
+        self.iter.index = index;
+        self.iter.get()
+    }
+
+    /// Like `seek`, relative to the cursor.
+    #[inline]
+    pub fn seek_relative(&mut self, offset: isize) -> Option<Indexed<'_, I::Item>> {
+        let index = self.iter.index.checked_add_signed(offset)?;
+        self.seek(index)
+    }
+}
+
+impl<I: Iterator> core::ops::Deref for SealedReiterator<I> {
+    type Target = Reiterator<I>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.iter
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Give up the public `index` field for good: from now on, the cursor moves only through checked methods.
+    #[inline(always)]
+    #[must_use]
+    pub const fn seal(self) -> SealedReiterator<I> {
+        SealedReiterator { iter: self }
+    }
+}
@@ -0,0 +1,145 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Fast equality search over contiguous caches (`Frozen`, `ContiguousReiterator`) of `u8`s and `u16`s, e.g. scanning a byte stream for a frame delimiter.
+//! With the `simd` feature (nightly), comparisons run a whole vector at a time; otherwise it's a plain scalar loop.
+//! A plain `Reiterator` boxes each element, so there's no slice to search: use the searches in `find` instead.
+
+use crate::{contiguous::ContiguousReiterator, frozen::Frozen};
+
+/// Keeps `Lane` closed to the types below.
+mod private {
+    /// Implemented only for types with a `Lane` implementation here.
+    pub trait Sealed {}
+}
+
+/// Element type we know how to search quickly.
+pub trait Lane: Copy + PartialEq + private::Sealed {
+    /// Index of the first element of `haystack` equal to `needle`.
+    #[must_use]
+    fn position(haystack: &[Self], needle: Self) -> Option<usize>;
+}
+
+/// Scalar search, for leftovers and when SIMD is off.
+#[inline]
+fn scalar<T: PartialEq>(haystack: &[T], needle: &T) -> Option<usize> {
+    haystack.iter().position(|item| item == needle)
+}
+
+/// Implement `Lane` for a primitive, vectorized `$lanes` at a time with the `simd` feature.
+macro_rules! lane {
+    ($t:ty, $lanes:literal) => {
+        impl private::Sealed for $t {}
+
+        impl Lane for $t {
+            #[cfg(feature = "simd")]
+            #[inline]
+            fn position(haystack: &[Self], needle: Self) -> Option<usize> {
+                use core::simd::{cmp::SimdPartialEq, Simd};
+                let splat = Simd::<$t, $lanes>::splat(needle);
+                let mut chunks = haystack.chunks_exact($lanes);
+                let mut offset = 0_usize;
+                for chunk in &mut chunks {
+                    let bits = Simd::<$t, $lanes>::from_slice(chunk)
+                        .simd_eq(splat)
+                        .to_bitmask();
+                    if bits != 0 {
+                        return Some(offset.wrapping_add(bits.trailing_zeros() as usize));
+                    }
+                    offset = offset.wrapping_add($lanes);
+                }
+                scalar(chunks.remainder(), &needle).map(|index| offset.wrapping_add(index))
+            }
+
+            #[cfg(not(feature = "simd"))]
+            #[inline(always)]
+            fn position(haystack: &[Self], needle: Self) -> Option<usize> {
+                scalar(haystack, &needle)
+            }
+        }
+    };
+}
+lane!(u8, 32);
+lane!(u16, 16);
+
+/// Index of the first element of `haystack` equal to `needle`.
+#[inline(always)]
+#[must_use]
+pub fn position<T: Lane>(haystack: &[T], needle: T) -> Option<usize> {
+    T::position(haystack, needle)
+}
+
+/// Index at which `needle` first appears as a contiguous run in `haystack` (`Some(0)` if `needle` is empty).
+/// Jumps between candidate starts with `position`, so only real candidates get a full comparison.
+#[inline]
+#[must_use]
+pub fn find_subsequence<T: Lane>(haystack: &[T], needle: &[T]) -> Option<usize> {
+    let Some((&first, rest)) = needle.split_first() else {
+        return Some(0);
+    };
+    let last_start = haystack.len().checked_sub(needle.len())?;
+    let mut start = 0;
+    while start <= last_start {
+        let candidate = start.wrapping_add(position(haystack.get(start..=last_start)?, first)?);
+        if haystack
+            .get(candidate.wrapping_add(1)..)
+            .is_some_and(|after| after.starts_with(rest))
+        {
+            return Some(candidate);
+        }
+        start = candidate.wrapping_add(1);
+    }
+    None
+}
+
+impl<T: Lane> Frozen<T> {
+    /// Index of the first element equal to `needle`.
+    #[inline(always)]
+    #[must_use]
+    pub fn position_cached(&self, needle: T) -> Option<usize> {
+        position(self, needle)
+    }
+
+    /// Whether any element equals `needle`.
+    #[inline(always)]
+    #[must_use]
+    pub fn contains(&self, needle: T) -> bool {
+        self.position_cached(needle).is_some()
+    }
+
+    /// Index at which `needle` first appears as a contiguous run.
+    #[inline(always)]
+    #[must_use]
+    pub fn find_subsequence(&self, needle: &[T]) -> Option<usize> {
+        find_subsequence(self, needle)
+    }
+}
+
+impl<I: Iterator> ContiguousReiterator<I>
+where
+    I::Item: Lane,
+{
+    /// Index of the first element computed so far equal to `needle`. Never computes anything.
+    #[inline(always)]
+    #[must_use]
+    pub fn position_cached(&self, needle: I::Item) -> Option<usize> {
+        position(self.cached(), needle)
+    }
+
+    /// Whether any element computed so far equals `needle`. Never computes anything.
+    #[inline(always)]
+    #[must_use]
+    pub fn contains(&self, needle: I::Item) -> bool {
+        self.position_cached(needle).is_some()
+    }
+
+    /// Index at which `needle` first appears as a contiguous run among the elements computed so far. Never computes anything.
+    #[inline(always)]
+    #[must_use]
+    pub fn find_subsequence(&self, needle: &[I::Item]) -> Option<usize> {
+        find_subsequence(self.cached(), needle)
+    }
+}
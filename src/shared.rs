@@ -0,0 +1,316 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Frozen caches in named POSIX shared memory, so sibling processes can read one materialized sequence without serializing it.
+//! The segment starts with a small header (length, element size, checksum) that `open_shared` checks before handing out a slice.
+
+#![allow(unsafe_code)]
+
+use crate::{checksum::Fnv1a, frozen::Frozen};
+use core::hash::Hasher;
+use std::{ffi::CString, io};
+
+/// Element type that can be copied byte-for-byte into another process and read back:
+/// no padding, no pointers, and every bit pattern is a valid value.
+/// # Safety
+/// Implementing this for anything that doesn't satisfy all three is undefined behavior the moment another process reads it.
+pub unsafe trait Plain: Copy {}
+
+/// Implement `Plain` for primitives.
+macro_rules! plain {
+    ($($t:ty),*) => { $(unsafe impl Plain for $t {})* };
+}
+plain!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+unsafe impl<T: Plain, const N: usize> Plain for [T; N] {}
+
+/// Identifies a segment written by this crate.
+const MAGIC: [u8; 8] = *b"REITSHM\0";
+
+/// Layout at the very start of the segment.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Header {
+    /// Always `MAGIC`.
+    magic: [u8; 8],
+    /// Number of elements.
+    len: u64,
+    /// `size_of::<Item>()` of the process that wrote it.
+    element_size: u64,
+    /// FNV-1a over the element bytes.
+    checksum: u64,
+}
+
+/// FNV-1a over raw bytes.
+#[inline]
+fn checksum(bytes: &[u8]) -> u64 {
+    let mut hasher = Fnv1a::default();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// Byte offset of the first element: right after the header, rounded up to `Item`'s alignment.
+#[inline]
+const fn items_offset<Item>() -> usize {
+    let align = align_of::<Item>();
+    let header = size_of::<Header>();
+    header.wrapping_add(align).wrapping_sub(1) / align * align
+}
+
+/// Turn a `-1` from libc into the last OS error.
+#[inline]
+fn check(ret: libc::c_int) -> io::Result<libc::c_int> {
+    if ret == -1 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(ret)
+    }
+}
+
+/// Error for anything that isn't a segment we wrote for this element type.
+#[inline]
+fn invalid(why: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, why)
+}
+
+/// Read-only mapping of a frozen sequence in shared memory. Dereferences to a slice.
+/// The process that created the segment removes its name when this is dropped; anyone who already opened it keeps their mapping.
+#[derive(Debug)]
+pub struct SharedFrozen<Item: Plain> {
+    /// Start of the mapping (the header).
+    base: core::ptr::NonNull<u8>,
+    /// Length of the mapping in bytes.
+    map_len: usize,
+    /// Number of elements.
+    len: usize,
+    /// Segment name, if we created it and should unlink it.
+    owned: Option<CString>,
+    /// We hand out `&Item`s.
+    marker: core::marker::PhantomData<Item>,
+}
+
+// SAFETY: the mapping is read-only and `Item: Plain` has no interior mutability or pointers.
+unsafe impl<Item: Plain + Sync> Send for SharedFrozen<Item> {}
+// SAFETY: see above.
+unsafe impl<Item: Plain + Sync> Sync for SharedFrozen<Item> {}
+
+impl<Item: Plain> SharedFrozen<Item> {
+    /// Map a segment read-only and check its header against `Item`.
+    /// # Errors
+    /// If the segment can't be opened or mapped, or wasn't written by `share` for an `Item` of this size, or its checksum doesn't match.
+    #[inline]
+    pub fn open_shared(name: &str) -> io::Result<Self> {
+        let c_name = CString::new(name).map_err(|_| invalid("name contains a NUL byte"))?;
+        // SAFETY: valid C string.
+        let fd = check(unsafe { libc::shm_open(c_name.as_ptr(), libc::O_RDONLY, 0) })?;
+        // SAFETY: `stat` is plain old data and `fstat` fills it in.
+        let mut stat: libc::stat = unsafe { core::mem::zeroed() };
+        // SAFETY: `fd` is open.
+        let stat_result = check(unsafe { libc::fstat(fd, &mut stat) });
+        let mapped = stat_result.and_then(|_| {
+            let map_len =
+                usize::try_from(stat.st_size).map_err(|_| invalid("negative segment size"))?;
+            // SAFETY: `fd` is open and we only ever read through this mapping.
+            unsafe { Self::map(fd, map_len, libc::PROT_READ, None) }
+        });
+        // SAFETY: the mapping (if any) outlives the descriptor.
+        let _ = unsafe { libc::close(fd) };
+        mapped?.validate()
+    }
+
+    /// Create a segment called `name` holding a copy of `items`.
+    /// Fails if the name is already taken rather than clobbering someone else's segment.
+    /// # Errors
+    /// If the segment can't be created, sized, or mapped.
+    #[inline]
+    pub fn share(name: &str, items: &[Item]) -> io::Result<Self> {
+        let c_name = CString::new(name).map_err(|_| invalid("name contains a NUL byte"))?;
+        let map_len = size_of_val(items)
+            .checked_add(items_offset::<Item>())
+            .ok_or_else(|| invalid("too many elements"))?;
+        // SAFETY: valid C string.
+        let fd = check(unsafe {
+            libc::shm_open(
+                c_name.as_ptr(),
+                libc::O_RDWR | libc::O_CREAT | libc::O_EXCL,
+                0o600,
+            )
+        })?;
+        let sized = libc::off_t::try_from(map_len)
+            .map_err(|_| invalid("segment too large"))
+            // SAFETY: `fd` is open for writing.
+            .and_then(|off| check(unsafe { libc::ftruncate(fd, off) }));
+        let mapped = sized.and_then(|_| {
+            // SAFETY: `fd` is open for reading and writing.
+            unsafe {
+                Self::map(
+                    fd,
+                    map_len,
+                    libc::PROT_READ | libc::PROT_WRITE,
+                    Some(c_name.clone()),
+                )
+            }
+        });
+        // SAFETY: the mapping (if any) outlives the descriptor.
+        let _ = unsafe { libc::close(fd) };
+        let mut shared = match mapped {
+            Ok(shared) => shared,
+            Err(e) => {
+                // SAFETY: valid C string.
+                let _ = unsafe { libc::shm_unlink(c_name.as_ptr()) };
+                return Err(e);
+            }
+        };
+        let payload = shared.base.as_ptr().wrapping_add(items_offset::<Item>());
+        // SAFETY: the mapping is writable, large enough, and aligned (page-aligned base, aligned offset).
+        unsafe {
+            core::ptr::copy_nonoverlapping(items.as_ptr(), payload.cast::<Item>(), items.len());
+        }
+        let header = Header {
+            magic: MAGIC,
+            len: items.len() as u64,
+            element_size: size_of::<Item>() as u64,
+            checksum: checksum(shared.payload()),
+        };
+        // SAFETY: the mapping is writable and page-aligned, and `Header` fits before the payload.
+        unsafe {
+            shared.base.as_ptr().cast::<Header>().write(header);
+            if libc::mprotect(shared.base.as_ptr().cast(), map_len, libc::PROT_READ) == -1 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        shared.len = items.len();
+        Ok(shared)
+    }
+
+    /// `mmap` a descriptor as shared memory.
+    /// # Safety
+    /// `fd` must be open with permissions covering `prot`.
+    #[inline]
+    unsafe fn map(
+        fd: libc::c_int,
+        map_len: usize,
+        prot: libc::c_int,
+        owned: Option<CString>,
+    ) -> io::Result<Self> {
+        if map_len < items_offset::<Item>() {
+            return Err(invalid("segment too small for a header"));
+        }
+        // SAFETY: fresh mapping at an address of the kernel's choosing.
+        let ptr = unsafe {
+            libc::mmap(
+                core::ptr::null_mut(),
+                map_len,
+                prot,
+                libc::MAP_SHARED,
+                fd,
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self {
+            base: core::ptr::NonNull::new(ptr.cast()).ok_or_else(|| invalid("null mapping"))?,
+            map_len,
+            len: 0,
+            owned,
+            marker: core::marker::PhantomData,
+        })
+    }
+
+    /// Every byte after the header.
+    #[inline]
+    fn payload(&self) -> &[u8] {
+        let offset = items_offset::<Item>();
+        // SAFETY: `map` guarantees the mapping is at least `offset` bytes long.
+        unsafe {
+            core::slice::from_raw_parts(
+                self.base.as_ptr().wrapping_add(offset),
+                self.map_len.wrapping_sub(offset),
+            )
+        }
+    }
+
+    /// Check the header against `Item` and the payload, then trust its length.
+    #[inline]
+    fn validate(mut self) -> io::Result<Self> {
+        // SAFETY: `map` guarantees the header is in bounds, and the base is page-aligned.
+        let header = unsafe { self.base.as_ptr().cast::<Header>().read() };
+        if header.magic != MAGIC {
+            return Err(invalid("not a shared frozen cache"));
+        }
+        if header.element_size != size_of::<Item>() as u64 {
+            return Err(invalid("element size mismatch"));
+        }
+        let len = usize::try_from(header.len).map_err(|_| invalid("length overflow"))?;
+        if len.checked_mul(size_of::<Item>()) != Some(self.payload().len()) {
+            return Err(invalid("length doesn't match segment size"));
+        }
+        if header.checksum != checksum(self.payload()) {
+            return Err(invalid("checksum mismatch"));
+        }
+        self.len = len;
+        Ok(self)
+    }
+
+    /// Number of elements.
+    #[inline(always)]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no elements.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Copy every element back into this process's memory.
+    #[inline]
+    #[must_use]
+    pub fn to_frozen(&self) -> Frozen<Item> {
+        self.iter().copied().collect()
+    }
+}
+
+impl<Item: Plain> core::ops::Deref for SharedFrozen<Item> {
+    type Target = [Item];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        let items = self
+            .base
+            .as_ptr()
+            .wrapping_add(items_offset::<Item>())
+            .cast::<Item>();
+        // SAFETY: validated (or written) `len` aligned elements, and `Item: Plain` so any bytes are a valid value.
+        unsafe { core::slice::from_raw_parts(items, self.len) }
+    }
+}
+
+impl<Item: Plain> Drop for SharedFrozen<Item> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: we mapped exactly this range and nothing borrows it past `self`.
+        let _ = unsafe { libc::munmap(self.base.as_ptr().cast(), self.map_len) };
+        if let Some(ref name) = self.owned {
+            // SAFETY: valid C string.
+            let _ = unsafe { libc::shm_unlink(name.as_ptr()) };
+        }
+    }
+}
+
+impl<Item: Plain> Frozen<Item> {
+    /// Copy every element into a new shared-memory segment called `name`, for other processes to `SharedFrozen::open_shared`.
+    /// # Errors
+    /// See `SharedFrozen::share`.
+    #[inline(always)]
+    pub fn share(&self, name: &str) -> io::Result<SharedFrozen<Item>> {
+        SharedFrozen::share(name, self)
+    }
+}
@@ -0,0 +1,147 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Views that skip a prefix once, remember where it ended, and rebase every index after it: `at(0)` is the first retained element.
+
+use crate::{indexed::Indexed, Reiterator};
+
+/// View skipping the first `offset` elements.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct Skip<I: Iterator> {
+    /// Unskipped cache.
+    iter: Reiterator<I>,
+
+    /// Number of elements skipped.
+    offset: usize,
+
+    /// Safe to edit! Index in the _rebased_ index space. See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<I: Iterator> Skip<I> {
+    /// Index in the source of the element at `index` in this view.
+    #[inline(always)]
+    #[must_use]
+    pub const fn source_index(&self, index: usize) -> Option<usize> {
+        self.offset.checked_add(index)
+    }
+
+    /// Return the element at the requested (rebased) index *or compute it if we haven't*, provided it's in bounds.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&I::Item> {
+        let source = self.source_index(index)?;
+        self.iter.at(source)
+    }
+
+    /// Whether `at(index)` is `None` only because the element was evicted, so `next` should skip it.
+    #[inline]
+    fn evicted(&mut self, index: usize) -> bool {
+        self.source_index(index)
+            .is_some_and(|source| self.iter.cache.is_evicted(source))
+    }
+
+    crate::view::cursor!(I::Item);
+
+    crate::view::into_inner!(iter: Reiterator<I>);
+}
+
+/// View skipping every element before the first one failing a predicate.
+/// The predicate runs only until that element is found, then never again.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct SkipWhile<I: Iterator, Predicate: FnMut(&I::Item) -> bool> {
+    /// Unskipped cache.
+    iter: Reiterator<I>,
+
+    /// Run only until we find the offset.
+    predicate: Predicate,
+
+    /// Number of elements skipped, once we know it.
+    offset: Option<usize>,
+
+    /// Safe to edit! Index in the _rebased_ index space. See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<I: Iterator, Predicate: FnMut(&I::Item) -> bool> SkipWhile<I, Predicate> {
+    /// Number of elements skipped, testing elements to find out if we haven't already.
+    /// Evicted elements can't be tested, so they're skipped.
+    #[inline]
+    pub fn offset(&mut self) -> usize {
+        if let Some(offset) = self.offset {
+            return offset;
+        }
+        let mut index = 0_usize;
+        loop {
+            if !self.iter.cache.is_evicted(index) {
+                match self.iter.at(index) {
+                    Some(item) if (self.predicate)(item) => {}
+                    _ => break,
+                }
+            }
+            match index.checked_add(1) {
+                Some(incr) => index = incr,
+                None => break,
+            }
+        }
+        self.offset = Some(index);
+        index
+    }
+
+    /// Index in the source of the element at `index` in this view.
+    #[inline(always)]
+    #[must_use]
+    pub fn source_index(&mut self, index: usize) -> Option<usize> {
+        self.offset().checked_add(index)
+    }
+
+    /// Return the element at the requested (rebased) index *or compute it if we haven't*, provided it's in bounds.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&I::Item> {
+        let source = self.source_index(index)?;
+        self.iter.at(source)
+    }
+
+    /// Whether `at(index)` is `None` only because the element was evicted, so `next` should skip it.
+    #[inline]
+    fn evicted(&mut self, index: usize) -> bool {
+        self.source_index(index)
+            .is_some_and(|source| self.iter.cache.is_evicted(source))
+    }
+
+    crate::view::cursor!(I::Item);
+
+    crate::view::into_inner!(iter: Reiterator<I>);
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// View skipping the first `offset` elements, sharing this cache.
+    #[inline(always)]
+    #[must_use]
+    pub const fn skip(self, offset: usize) -> Skip<I> {
+        Skip {
+            iter: self,
+            offset,
+            index: 0,
+        }
+    }
+
+    /// View skipping every element before the first one failing `predicate`, sharing this cache.
+    #[inline(always)]
+    #[must_use]
+    pub const fn skip_while<Predicate: FnMut(&I::Item) -> bool>(
+        self,
+        predicate: Predicate,
+    ) -> SkipWhile<I, Predicate> {
+        SkipWhile {
+            iter: self,
+            predicate,
+            offset: None,
+            index: 0,
+        }
+    }
+}
@@ -0,0 +1,177 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Versioned on-disk format for cached elements, so an application can change its `Item` type without orphaning existing caches.
+//! You supply the per-element encoding; we add a small header (magic, your format version, element count) and length-prefix each element.
+//! On load, elements written under an older version go through your migration callback instead of your decoder.
+
+use crate::{cache::Evicted, Reiterator};
+use ::alloc::vec::Vec;
+
+/// Identifies a snapshot written by this crate.
+const MAGIC: [u8; 8] = *b"REITSNAP";
+
+/// Bytes before the first element: magic, version (`u32`), element count (`u64`), all little-endian.
+const HEADER_LEN: usize = 20;
+
+/// Everything computed so far, tagged with the version of your element encoding.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Snapshot<Item> {
+    /// Version of the element encoding, chosen by you. Bump it whenever the encoding changes.
+    pub version: u32,
+    /// Every element, in order.
+    pub items: Vec<Item>,
+}
+
+/// Why a byte buffer couldn't be loaded as a snapshot.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum SnapshotError {
+    /// Doesn't start with our header.
+    NotASnapshot,
+    /// Ends partway through the header or an element.
+    Truncated,
+    /// Written under a newer version than we know how to read.
+    TooNew {
+        /// Version in the header.
+        version: u32,
+        /// Newest version we were told about.
+        current: u32,
+    },
+    /// Your decoder (or migration callback) rejected an element.
+    Item {
+        /// Index of the rejected element.
+        index: usize,
+    },
+}
+
+impl core::fmt::Display for SnapshotError {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::NotASnapshot => write!(f, "not a reiterator snapshot"),
+            Self::Truncated => write!(f, "snapshot ends unexpectedly"),
+            Self::TooNew { version, current } => write!(
+                f,
+                "snapshot has version {version}, but the newest we can read is {current}",
+            ),
+            Self::Item { index } => write!(f, "couldn't decode element #{index}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SnapshotError {}
+
+/// Split off the first `n` bytes, if there are that many.
+#[inline]
+fn take<'bytes>(bytes: &mut &'bytes [u8], n: usize) -> Result<&'bytes [u8], SnapshotError> {
+    if bytes.len() < n {
+        return Err(SnapshotError::Truncated);
+    }
+    let (head, tail) = bytes.split_at(n);
+    *bytes = tail;
+    Ok(head)
+}
+
+/// Read a little-endian `u64` length and narrow it to `usize`.
+#[inline]
+fn take_len(bytes: &mut &[u8]) -> Result<usize, SnapshotError> {
+    let mut le = [0; 8];
+    le.copy_from_slice(take(bytes, 8)?);
+    usize::try_from(u64::from_le_bytes(le)).map_err(|_| SnapshotError::Truncated)
+}
+
+impl<Item> Snapshot<Item> {
+    /// Serialize, calling `encode` to append each element's bytes.
+    #[inline]
+    #[must_use]
+    pub fn encode<Encode: FnMut(&Item, &mut Vec<u8>)>(&self, mut encode: Encode) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN);
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&self.version.to_le_bytes());
+        bytes.extend_from_slice(&(self.items.len() as u64).to_le_bytes());
+        let mut scratch = Vec::new();
+        for item in &self.items {
+            scratch.clear();
+            encode(item, &mut scratch);
+            bytes.extend_from_slice(&(scratch.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(&scratch);
+        }
+        bytes
+    }
+
+    /// Deserialize a snapshot written under any version up to `current`.
+    /// Elements written under `current` go to `decode`; older ones go to `migrate` along with the version they were written under.
+    /// Either can return `None` to reject an element. The result is always tagged `current`.
+    /// # Errors
+    /// If the bytes aren't a complete snapshot, were written under a version newer than `current`, or an element is rejected.
+    #[inline]
+    pub fn decode<
+        Decode: FnMut(&[u8]) -> Option<Item>,
+        Migrate: FnMut(u32, &[u8]) -> Option<Item>,
+    >(
+        mut bytes: &[u8],
+        current: u32,
+        mut decode: Decode,
+        mut migrate: Migrate,
+    ) -> Result<Self, SnapshotError> {
+        if take(&mut bytes, MAGIC.len()).map_err(|_| SnapshotError::NotASnapshot)? != MAGIC {
+            return Err(SnapshotError::NotASnapshot);
+        }
+        let mut le = [0; 4];
+        le.copy_from_slice(take(&mut bytes, 4)?);
+        let version = u32::from_le_bytes(le);
+        if version > current {
+            return Err(SnapshotError::TooNew { version, current });
+        }
+        let len = take_len(&mut bytes)?;
+        let mut items = Vec::with_capacity(len.min(bytes.len() / 8));
+        for index in 0..len {
+            let item_len = take_len(&mut bytes)?;
+            let item_bytes = take(&mut bytes, item_len)?;
+            let item = if version == current {
+                decode(item_bytes)
+            } else {
+                migrate(version, item_bytes)
+            };
+            items.push(item.ok_or(SnapshotError::Item { index })?);
+        }
+        Ok(Self {
+            version: current,
+            items,
+        })
+    }
+}
+
+impl<I: Iterator> Reiterator<I>
+where
+    I::Item: Clone,
+{
+    /// Copy every element computed so far into a snapshot tagged `version`.
+    /// # Errors
+    /// If any element has been evicted, since a snapshot couldn't keep its indices intact.
+    #[inline]
+    pub fn snapshot(&self, version: u32) -> Result<Snapshot<I::Item>, Evicted> {
+        (0..self.cached_len())
+            .map(|index| self.cache.cached(index).cloned().ok_or(Evicted { index }))
+            .collect::<Result<_, _>>()
+            .map(|items| Snapshot { version, items })
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Start with a loaded snapshot already cached and `into_iter` producing everything after it.
+    /// Same as `with_warm_cache(snapshot.items, into_iter)`.
+    #[inline(always)]
+    pub fn from_snapshot<II: IntoIterator<IntoIter = I>>(
+        snapshot: Snapshot<I::Item>,
+        into_iter: II,
+    ) -> Self {
+        Self::with_warm_cache(snapshot.items, into_iter)
+    }
+}
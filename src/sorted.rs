@@ -0,0 +1,108 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Sorted view of a fully computed cache: a permutation of indices, so nothing is cloned or moved.
+
+use crate::{
+    cache::{Cache, CacheFull},
+    indexed::Indexed,
+    Reiterator,
+};
+use ::alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Every cached element in sorted order, by reference. Ties keep their original order. Evicted elements are left out.
+#[allow(missing_debug_implementations)]
+pub struct SortedView<'cache, I: Iterator> {
+    /// Fully computed cache.
+    cache: &'cache Cache<I>,
+
+    /// Index of each element, in sorted order.
+    order: Vec<usize>,
+}
+
+impl<'cache, I: Iterator> SortedView<'cache, I> {
+    /// The element of rank `rank` (`0` is the smallest) along with its original index, provided it's in bounds.
+    #[inline]
+    #[must_use]
+    pub fn at(&self, rank: usize) -> Option<Indexed<'cache, I::Item>> {
+        let index = *self.order.get(rank)?;
+        Some(Indexed {
+            index,
+            value: self.cache.cached(index)?,
+        })
+    }
+
+    /// Every element in sorted order, each with its original index.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Indexed<'cache, I::Item>> + '_ {
+        let cache = self.cache;
+        self.order.iter().filter_map(move |&index| {
+            Some(Indexed {
+                index,
+                value: cache.cached(index)?,
+            })
+        })
+    }
+
+    /// Original index of each element, in sorted order.
+    #[inline(always)]
+    #[must_use]
+    pub fn permutation(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Number of elements in the view.
+    #[inline(always)]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Whether the view has no elements.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Exhaust the source (if we haven't already), then sort every element by `Ord` without cloning anything.
+    /// Never returns if the source is infinite. The cursor is left alone.
+    /// # Errors
+    /// If `Overflow::Refuse` stopped us before the source ended.
+    #[inline(always)]
+    pub fn sorted_view(&mut self) -> Result<SortedView<'_, I>, CacheFull>
+    where
+        I::Item: Ord,
+    {
+        self.sorted_view_by(Ord::cmp)
+    }
+
+    /// Exhaust the source (if we haven't already), then sort every element by `compare` without cloning anything.
+    /// Never returns if the source is infinite. The cursor is left alone.
+    /// # Errors
+    /// If `Overflow::Refuse` stopped us before the source ended.
+    #[inline]
+    pub fn sorted_view_by<Compare: FnMut(&I::Item, &I::Item) -> Ordering>(
+        &mut self,
+        mut compare: Compare,
+    ) -> Result<SortedView<'_, I>, CacheFull> {
+        let len = self.cache.exhaust();
+        self.notify();
+        let len = len?;
+        let cache = &self.cache;
+        let mut order: Vec<usize> = (0..len)
+            .filter(|&index| cache.cached(index).is_some())
+            .collect();
+        order.sort_by(|&a, &b| match (cache.cached(a), cache.cached(b)) {
+            (Some(a), Some(b)) => compare(a, b),
+            _ => Ordering::Equal,
+        });
+        Ok(SortedView { cache, order })
+    }
+}
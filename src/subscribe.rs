@@ -0,0 +1,184 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Batched observers: callbacks that fire on each completed block of newly cached elements.
+
+use crate::{indexed::Indexed, Reiterator};
+use ::alloc::boxed::Box;
+use core::mem::ManuallyDrop;
+
+/// Anything that can be handed each completed block of newly cached elements (evicted elements are skipped).
+/// Needn't be `Send`: it's only ever called (or dropped) on the thread that subscribed it, so the `Reiterator` can still cross threads.
+/// Anywhere else, its blocks wait (and aren't evicted) until the `Reiterator` comes back; if it's dropped anywhere else, so is the callback, without running its destructor.
+#[cfg(feature = "std")]
+pub trait Observer<Item>:
+    for<'a> FnMut(&mut dyn Iterator<Item = Indexed<'a, Item>>) + 'static
+{
+}
+
+#[cfg(feature = "std")]
+impl<Item, F: for<'a> FnMut(&mut dyn Iterator<Item = Indexed<'a, Item>>) + 'static> Observer<Item>
+    for F
+{
+}
+
+/// Anything that can be handed each completed block of newly cached elements (evicted elements are skipped).
+/// Without `std` there's no telling threads apart, so it has to be `Send` for the `Reiterator` to cross threads.
+#[cfg(not(feature = "std"))]
+pub trait Observer<Item>:
+    for<'a> FnMut(&mut dyn Iterator<Item = Indexed<'a, Item>>) + Send + 'static
+{
+}
+
+#[cfg(not(feature = "std"))]
+impl<Item, F: for<'a> FnMut(&mut dyn Iterator<Item = Indexed<'a, Item>>) + Send + 'static>
+    Observer<Item> for F
+{
+}
+
+/// A subscriber's callback, kept on the thread that subscribed it.
+pub(crate) struct Callback<Item> {
+    /// Only ever called or dropped on `owner`.
+    observer: ManuallyDrop<Box<dyn Observer<Item>>>,
+
+    /// Thread that subscribed.
+    #[cfg(feature = "std")]
+    owner: std::thread::ThreadId,
+}
+
+#[allow(unsafe_code)]
+// SAFETY: `observer` is only ever touched on `owner` (see `at_home`); anywhere else, it's moved around but never used.
+#[cfg(feature = "std")]
+unsafe impl<Item> Send for Callback<Item> {}
+
+impl<Item> Callback<Item> {
+    /// Keep `observer` on this thread.
+    #[inline(always)]
+    pub(crate) fn new<Subscriber: Observer<Item>>(observer: Subscriber) -> Self {
+        Self {
+            observer: ManuallyDrop::new(Box::new(observer)),
+            #[cfg(feature = "std")]
+            owner: std::thread::current().id(),
+        }
+    }
+
+    /// Whether we're on the thread that subscribed, so `observer` can be touched.
+    #[inline(always)]
+    fn at_home(&self) -> bool {
+        #[cfg(feature = "std")]
+        return std::thread::current().id() == self.owner;
+        #[cfg(not(feature = "std"))]
+        true
+    }
+}
+
+impl<Item> Drop for Callback<Item> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.at_home() {
+            #[allow(unsafe_code)]
+            // SAFETY: We're being dropped, so `observer` is never touched again.
+            unsafe {
+                ManuallyDrop::drop(&mut self.observer);
+            }
+        }
+    }
+}
+
+/// One subscriber and how far it's gotten.
+pub(crate) struct Subscription<Item> {
+    /// Number of elements per block.
+    block_len: usize,
+
+    /// Index of the first element of the next block this subscriber hasn't seen.
+    next_start: usize,
+
+    /// What to do with each block.
+    callback: Callback<Item>,
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// Call `callback` with every block of `block_len` consecutive elements cached from now on, as soon as the whole block is cached.
+    /// Blocks start at whatever we've computed so far; a `block_len` of zero is treated as one.
+    /// Nothing is evicted until it's been handed over, so a window or `Overflow::Evict` cap may be exceeded until then.
+    #[inline]
+    pub fn subscribe<Subscriber: Observer<I::Item>>(
+        &mut self,
+        block_len: usize,
+        callback: Subscriber,
+    ) {
+        self.subscribe_from(self.cache.cached_len(), block_len, Callback::new(callback));
+    }
+
+    /// Like `subscribe`, but with blocks starting at `start` (which may already be cached) instead of wherever we are now.
+    #[inline]
+    pub(crate) fn subscribe_from(
+        &mut self,
+        start: usize,
+        block_len: usize,
+        callback: Callback<I::Item>,
+    ) {
+        self.subscriptions.push(Subscription {
+            block_len: block_len.max(1),
+            next_start: start,
+            callback,
+        });
+        self.pin_owed();
+    }
+
+    /// Hand every newly completed block to everyone waiting on it (who's on this thread).
+    /// Never moves or evicts anything.
+    #[inline]
+    pub(crate) fn notify(&mut self) {
+        let cached = self.cache.cached_len();
+        for subscription in &mut self.subscriptions {
+            if !subscription.callback.at_home() {
+                continue;
+            }
+            while let Some(end) = subscription
+                .next_start
+                .checked_add(subscription.block_len)
+                .filter(|&end| end <= cached)
+            {
+                (subscription.callback.observer)(
+                    &mut self.cache.cached_range(subscription.next_start..end),
+                );
+                subscription.next_start = end;
+            }
+        }
+        self.pin_owed();
+    }
+
+    /// Make every subscriber that's seen past `len` start over from there, e.g. once the cache has been truncated.
+    #[inline]
+    pub(crate) fn rewind_subscriptions(&mut self, len: usize) {
+        for subscription in &mut self.subscriptions {
+            subscription.next_start = subscription.next_start.min(len);
+        }
+        self.pin_owed();
+    }
+
+    /// Keep the cache from evicting anything a subscriber is still waiting on (but not blocks the source ended before completing).
+    #[inline]
+    pub(crate) fn pin_owed(&mut self) {
+        let known_len = self.cache.known_len();
+        let owed = self
+            .subscriptions
+            .iter()
+            .filter(|subscription| {
+                known_len.map_or(true, |len| {
+                    subscription
+                        .next_start
+                        .saturating_add(subscription.block_len)
+                        <= len
+                })
+            })
+            .map(|subscription| subscription.next_start)
+            .min()
+            .unwrap_or(usize::MAX);
+        self.cache.pin_from(owed);
+    }
+}
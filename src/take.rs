@@ -0,0 +1,144 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Bounded views: cap a (possibly infinite) `Reiterator` at a fixed length or at the first element failing a predicate.
+//! Indices are the same as the source's; anything past the bound is `None` without ever touching the source.
+
+use crate::{indexed::Indexed, Reiterator};
+
+/// View of at most the first `limit` elements.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct Take<I: Iterator> {
+    /// Unbounded cache.
+    iter: Reiterator<I>,
+
+    /// Number of elements in this view (at most).
+    limit: usize,
+
+    /// Safe to edit! See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<I: Iterator> Take<I> {
+    /// Return the element at the requested index *or compute it if we haven't*, provided it's within both the source and the limit.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&I::Item> {
+        if index < self.limit {
+            self.iter.at(index)
+        } else {
+            None
+        }
+    }
+
+    /// Whether `at(index)` is `None` only because the element was evicted, so `next` should skip it.
+    #[inline]
+    fn evicted(&mut self, index: usize) -> bool {
+        index < self.limit && self.iter.cache.is_evicted(index)
+    }
+
+    crate::view::cursor!(I::Item);
+
+    crate::view::into_inner!(iter: Reiterator<I>);
+}
+
+/// View of every element up to (but not including) the first one failing a predicate.
+/// The predicate runs at most once per element, and never past the boundary once it's been found.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct TakeWhile<I: Iterator, Predicate: FnMut(&I::Item) -> bool> {
+    /// Unbounded cache.
+    iter: Reiterator<I>,
+
+    /// Run at most once per element.
+    predicate: Predicate,
+
+    /// Number of leading elements known to pass.
+    passed: usize,
+
+    /// Index of the first element that failed, once we've found it.
+    boundary: Option<usize>,
+
+    /// Safe to edit! See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<I: Iterator, Predicate: FnMut(&I::Item) -> bool> TakeWhile<I, Predicate> {
+    /// Test elements until we know whether `index` is before the boundary.
+    /// Evicted elements can't be tested, so they count as passing.
+    #[inline]
+    fn in_bounds(&mut self, index: usize) -> bool {
+        while self.passed <= index {
+            if self.boundary.is_some() {
+                return false;
+            }
+            if !self.iter.cache.is_evicted(self.passed) {
+                match self.iter.at(self.passed) {
+                    Some(item) if (self.predicate)(item) => {}
+                    Some(_) => {
+                        self.boundary = Some(self.passed);
+                        return false;
+                    }
+                    None => return false,
+                }
+            }
+            match self.passed.checked_add(1) {
+                Some(incr) => self.passed = incr,
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// Return the element at the requested index *or compute it if we haven't*, provided it's before the boundary.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<&I::Item> {
+        if self.in_bounds(index) {
+            self.iter.at(index)
+        } else {
+            None
+        }
+    }
+
+    /// Whether `at(index)` is `None` only because the element was evicted, so `next` should skip it.
+    #[inline]
+    fn evicted(&mut self, index: usize) -> bool {
+        self.iter.cache.is_evicted(index) && self.in_bounds(index)
+    }
+
+    crate::view::cursor!(I::Item);
+
+    crate::view::into_inner!(iter: Reiterator<I>);
+}
+
+impl<I: Iterator> Reiterator<I> {
+    /// View of at most the first `limit` elements, sharing this cache.
+    #[inline(always)]
+    #[must_use]
+    pub const fn take(self, limit: usize) -> Take<I> {
+        Take {
+            iter: self,
+            limit,
+            index: 0,
+        }
+    }
+
+    /// View of every element before the first one failing `predicate`, sharing this cache.
+    #[inline(always)]
+    #[must_use]
+    pub const fn take_while<Predicate: FnMut(&I::Item) -> bool>(
+        self,
+        predicate: Predicate,
+    ) -> TakeWhile<I, Predicate> {
+        TakeWhile {
+            iter: self,
+            predicate,
+            passed: 0,
+            boundary: None,
+            index: 0,
+        }
+    }
+}
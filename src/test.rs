@@ -68,7 +68,2484 @@ fn simple_range_doesnt_panic() {
     }
 }
 
+#[test]
+#[cfg(feature = "unstable")]
+fn retain_cached_keeps_indices() {
+    let mut iter = (0..8_u8).reiterate();
+    assert_eq!(iter.at(5), Some(&5));
+    iter.retain_cached(|i| i % 2 == 0);
+    assert_eq!(iter.at(1), None);
+    assert_eq!(iter.at(2), Some(&2));
+    assert_eq!(iter.at(7), Some(&7)); // Not cached at the time, so not affected.
+    assert_eq!(
+        iter.map(|indexed| (indexed.index, *indexed.value))
+            .collect::<Vec<_>>(),
+        vec![(0, 0), (2, 2), (4, 4), (6, 6), (7, 7)],
+    );
+}
+
+#[cfg(feature = "unstable")]
+#[allow(clippy::indexing_slicing, clippy::unwrap_used)]
+#[test]
+fn ref_reiterator_no_double_reference() {
+    let v = vec!['a', 'b', 'c'];
+    let mut iter = crate::refs::reiterate_refs(&v);
+    let b: Option<&char> = iter.at(1);
+    assert_eq!(b, Some(&'b'));
+    assert!(core::ptr::eq(b.unwrap(), &v[1]));
+    assert_eq!(iter.by_ref().count(), 3);
+    iter.restart();
+    assert_eq!(iter.map(|i| i.index).collect::<Vec<_>>(), vec![0, 1, 2]);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn cow_reiterator_uniform_access() {
+    use ::alloc::{borrow::Cow, string::String};
+    let source = "let x = 1";
+    let mut iter = crate::cow::reiterate_cows(source.split(' ').map(|word| {
+        if word == "x" {
+            Cow::Owned(String::from("y"))
+        } else {
+            Cow::Borrowed(word)
+        }
+    }));
+    assert_eq!(iter.at(1), Some("y"));
+    assert_eq!(iter.at(3), Some("1"));
+    iter.index = 2;
+    assert_eq!(iter.next().map(|indexed| indexed.value), Some("="));
+    assert_eq!(iter.cached_len(), 4);
+    // Borrowed words point straight into the input; only "y" was built.
+    assert_eq!(iter.borrowed(0).map(str::as_ptr), Some(source.as_ptr()));
+    assert_eq!(iter.borrowed(1), None);
+    iter.restart();
+    let words: Vec<_> =
+        core::iter::from_fn(|| iter.next().map(|indexed| String::from(indexed.value))).collect();
+    assert_eq!(words, ["let", "y", "=", "1"]);
+}
+
+#[cfg(feature = "unstable")]
+#[allow(clippy::unwrap_used)]
+#[test]
+fn record_and_replay() {
+    use crate::replay::{record, replay, Trace};
+    use ::alloc::string::ToString;
+    let mut calls = 0_u32;
+    let mut iter = record(core::iter::from_fn(|| {
+        calls += 1;
+        (calls <= 3).then_some(calls * 10)
+    }));
+    assert_eq!(iter.at(2), Some(&30));
+    let text = iter.trace().to_string();
+    assert_eq!(text, "10\n20\n30\n");
+    let mut replayed = replay(Trace::<u32>::parse(&text).unwrap());
+    assert_eq!(replayed.at(1), Some(&20));
+    assert_eq!(replayed.at(3), None);
+}
+
+#[cfg(all(feature = "std", feature = "unstable"))]
+#[test]
+fn timeout_on_blocking_source() {
+    use crate::timeout::{with_timeout, TimedOut};
+    use std::time::Duration;
+    let source = (0..4_u8).map(|i| {
+        if i == 2 {
+            std::thread::sleep(Duration::from_millis(200));
+        }
+        i
+    });
+    let mut iter = with_timeout(source, Duration::from_millis(20)).reiterate();
+    assert_eq!(iter.try_at(1), Ok(Some(&1)));
+    assert_eq!(iter.try_at(2), Err(TimedOut));
+    assert_eq!(iter.at(3), Some(&3));
+    assert_eq!(iter.try_at(4), Ok(None));
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(all(feature = "std", feature = "unstable"))]
+#[test]
+fn receiver_history() {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut iter = crate::channel::reiterate_receiver(receiver);
+    for i in 0..3_u8 {
+        sender.send(i).unwrap();
+    }
+    assert_eq!(iter.at(2), Some(&2));
+    drop(sender);
+    assert_eq!(iter.at(3), None);
+    assert_eq!(iter.at(0), Some(&0));
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(all(feature = "std", feature = "unstable"))]
+#[test]
+fn history_buffer_window_and_tail() {
+    let (sender, mut history) =
+        crate::history::HistoryBuffer::new(core::num::NonZeroUsize::new(3).unwrap());
+    for i in 0..5_u8 {
+        sender.send(i).unwrap();
+    }
+    assert_eq!(history.at(4), Some(&4));
+    assert_eq!(history.at(1), None);
+    assert!(history.is_evicted(1));
+    assert_eq!(history.latest().map(|indexed| indexed.index), Some(4));
+    // Cursor at 0 has fallen out of the window, so it skips to the oldest retained event:
+    assert_eq!(history.next().map(|indexed| indexed.index), Some(2));
+    history.index = 5;
+    sender.send(5).unwrap();
+    assert_eq!(history.next().map(|indexed| *indexed.value), Some(5));
+    drop(sender);
+    assert_eq!(history.next(), None);
+}
+
+#[allow(clippy::unwrap_used)]
+#[cfg(all(feature = "std", feature = "unstable"))]
+#[test]
+fn history_buffer_of_one_hands_out_every_event() {
+    let (sender, mut history) = crate::history::HistoryBuffer::new(core::num::NonZeroUsize::MIN);
+    sender.send('a').unwrap();
+    assert_eq!(history.next().map(|indexed| *indexed.value), Some('a'));
+    sender.send('b').unwrap();
+    sender.send('c').unwrap();
+    drop(sender);
+    assert_eq!(
+        history
+            .next()
+            .map(|indexed| (indexed.index, *indexed.value)),
+        Some((2, 'c'))
+    );
+    assert_eq!(history.next(), None);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn delta_since_frontier() {
+    let mut iter = (0..10_u8).reiterate();
+    assert_eq!(iter.at(2), Some(&2));
+    let frontier = iter.frontier();
+    assert_eq!(iter.delta_since(frontier).count(), 0);
+    assert_eq!(iter.at(5), Some(&5));
+    assert_eq!(
+        iter.delta_since(frontier)
+            .map(|indexed| indexed.index)
+            .collect::<Vec<_>>(),
+        vec![3, 4, 5],
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+#[allow(clippy::unwrap_used)]
+fn subscribe_to_blocks() {
+    use std::sync::{Arc, Mutex};
+    let blocks = Arc::new(Mutex::new(Vec::new()));
+    let mut iter = (0..10_u8).reiterate();
+    assert_eq!(iter.at(0), Some(&0));
+    let sink = Arc::clone(&blocks);
+    iter.subscribe(3, move |block| {
+        sink.lock()
+            .unwrap()
+            .push(block.map(|indexed| *indexed.value).collect::<Vec<_>>());
+    });
+    assert_eq!(iter.at(5), Some(&5));
+    assert_eq!(*blocks.lock().unwrap(), vec![vec![1, 2, 3]]);
+    assert_eq!(iter.at(9), Some(&9));
+    assert_eq!(
+        *blocks.lock().unwrap(),
+        vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+#[allow(clippy::unwrap_used)]
+fn subscribers_see_blocks_before_eviction() {
+    use std::sync::{Arc, Mutex};
+    let blocks = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&blocks);
+    let mut iter = crate::Reiterator::builder(0_u8..20)
+        .window(core::num::NonZeroUsize::new(2).unwrap())
+        .subscribe(3, move |block| {
+            sink.lock()
+                .unwrap()
+                .push(block.map(|indexed| *indexed.value).collect::<Vec<_>>());
+        })
+        .build();
+    assert_eq!(iter.at(9), Some(&9));
+    assert_eq!(
+        *blocks.lock().unwrap(),
+        vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8]]
+    );
+    assert_eq!(iter.at(10), Some(&10));
+    assert_eq!(iter.at(0), None); // handed over, so evicted after all
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "unstable"))]
+fn subscribers_stay_on_their_thread() {
+    use std::{cell::RefCell, rc::Rc};
+    let blocks = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&blocks);
+    let mut iter = (0_u8..10).reiterate();
+    iter.subscribe(2, move |block| {
+        sink.borrow_mut()
+            .push(block.map(|indexed| *indexed.value).collect::<Vec<_>>());
+    });
+    std::thread::scope(|scope| {
+        let _ = scope.spawn(|| assert_eq!(iter.at(4), Some(&4)));
+    });
+    assert!(blocks.borrow().is_empty());
+    assert_eq!(iter.at(0), Some(&0));
+    assert_eq!(*blocks.borrow(), vec![vec![0, 1], vec![2, 3]]);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn indexed_owned_collects() {
+    use crate::indexed::OwnedIndexed;
+    let owned: Vec<OwnedIndexed<char>> = "ab".chars().reiterate().indexed_owned().collect();
+    assert_eq!(
+        owned,
+        vec![
+            OwnedIndexed {
+                index: 0,
+                value: 'a'
+            },
+            OwnedIndexed {
+                index: 1,
+                value: 'b'
+            },
+        ],
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn collect_range_and_all() {
+    let mut iter = "hello".chars().reiterate();
+    assert_eq!(iter.collect_range::<::alloc::string::String>(1..3), "el");
+    assert_eq!(iter.collect_range::<Vec<_>>(3..9), vec!['l', 'o']);
+    assert_eq!(iter.collect_all::<::alloc::string::String>(), "hello");
+    assert_eq!(iter.index, 0);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn collect_until_delimiter() {
+    let mut iter = "ab,c,".chars().reiterate();
+    assert_eq!(iter.collect_until(|&c| c == ','), vec!['a', 'b']);
+    assert_eq!(iter.index, 2);
+    let _ = iter.lazy_next();
+    assert_eq!(iter.collect_until(|&c| c == ','), vec!['c']);
+    let _ = iter.lazy_next();
+    assert_eq!(iter.collect_until(|&c| c == ','), vec![]);
+    assert_eq!(iter.index, 5);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn exact_reiterator_knows_len() {
+    let mut iter = crate::exact::reiterate_exact(vec![1_u8, 2, 3]);
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.at(3), None);
+    assert_eq!(iter.cached_len(), 0); // Rejected without probing.
+    assert_eq!(iter.at(1), Some(&2));
+    iter.index = 1;
+    let mapped = iter.map_values(|&v| v * 10);
+    assert_eq!(mapped.len(), 2);
+    assert_eq!(mapped.collect::<Vec<_>>(), vec![20, 30]);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn exact_adapters_lift_anything_that_would_cut_reads_short() {
+    use crate::cache::{Overflow, Probe};
+    use core::num::NonZeroUsize;
+    let mut iter = crate::exact::reiterate_exact(0_u8..10);
+    assert_eq!(iter.at(3), Some(&3));
+    iter.set_probe(Probe::CacheOnly);
+    iter.set_max_cached(4, Overflow::Refuse);
+    iter.set_window(NonZeroUsize::new(4));
+    let values = iter.map_values(|&value| value);
+    assert_eq!(values.len(), 10);
+    assert!(values.eq(0..10));
+    let mut pairs = crate::exact::reiterate_exact(vec!['a', 'b']).into_iter();
+    assert_eq!(pairs.len(), 2);
+    assert_eq!(pairs.next(), Some((0, 'a')));
+    assert_eq!(pairs.len(), 1);
+}
+
+#[test]
+#[cfg(feature = "nightly")]
+fn exact_adapters_are_trusted_len() {
+    fn trusted<I: core::iter::TrustedLen>(iter: I) -> I {
+        iter
+    }
+    let mut iter = crate::exact::reiterate_exact(0_u32..100);
+    assert_eq!(iter.at(10), Some(&10));
+    iter.index = 5;
+    let collected: Vec<_> = trusted(iter.map_values(|&value| value * 2)).collect();
+    assert_eq!(collected.capacity(), 95);
+    assert!(collected.into_iter().eq((5..100).map(|value| value * 2)));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn exact_reiterator_len_follows_the_cache() {
+    let mut iter = crate::exact::reiterate_exact(vec![1_u8, 2, 3]);
+    assert_eq!(iter.at(0), Some(&1));
+    assert_eq!(iter.push_front_of_source(4), Ok(()));
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.at(3), Some(&3));
+    let _ = iter.replace_source(vec![5, 6].into_iter());
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.at(4), Some(&5));
+    assert_eq!(iter.at(6), None);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn collect_into_reuses_vec() {
+    let mut iter = (0..6_u8).reiterate();
+    let mut buffer = Vec::with_capacity(16);
+    iter.collect_range_into(1..3, &mut buffer);
+    iter.collect_all_into(&mut buffer);
+    assert_eq!(buffer, vec![1, 2, 0, 1, 2, 3, 4, 5]);
+    assert!(buffer.capacity() >= 16);
+}
+
+#[cfg(feature = "unstable")]
+#[allow(clippy::unwrap_used)]
+#[test]
+fn freeze_moves_everything() {
+    use crate::cache::Evicted;
+    let mut iter = (0..5_u8).reiterate();
+    assert_eq!(iter.at(1), Some(&1));
+    let frozen = iter.freeze().unwrap();
+    assert_eq!(frozen.as_slice(), &[0, 1, 2, 3, 4]);
+    let mut evicting = (0..5_u8).reiterate();
+    assert_eq!(evicting.at(3), Some(&3));
+    evicting.retain_cached(|&i| i != 2);
+    assert_eq!(
+        evicting.freeze(),
+        Err(crate::frozen::FreezeError::Evicted(Evicted { index: 2 }))
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_freeze_in_order() {
+    use crate::frozen::{par_freeze, par_freeze_iter};
+    assert_eq!(par_freeze(1000, |i| i * 2).at(999), Some(&1998));
+    assert_eq!(
+        par_freeze_iter(0..1000_u32).into_vec(),
+        (0..1000).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn for_loop_over_reiterator() {
+    let mut iter = "abc".chars().reiterate();
+    assert_eq!(iter.at(2), Some(&'c'));
+    iter.index = 1;
+    let mut seen = vec![];
+    for (index, value) in iter {
+        seen.push((index, value));
+    }
+    assert_eq!(seen, vec![(1, 'b'), (2, 'c')]);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn eq_slice_bounded() {
+    let mut iter = (0..).reiterate();
+    assert!(!iter.eq_slice(&[0, 1, 2]));
+    assert_eq!(iter.cached_len(), 4);
+    let mut finite = (0..3).reiterate();
+    assert!(finite.eq_slice(&[0, 1, 2]));
+    assert!(!finite.eq_slice(&[0, 1]));
+    assert!(!finite.eq_slice(&[0, 1, 3]));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn mapped_views_stay_seekable() {
+    let mut values = (0..5_u8).reiterate().map_values(|&v| v * 2);
+    assert_eq!(values.at(3), Some(6));
+    values.set_index(4);
+    assert_eq!(values.next(), Some(8));
+    values.restart();
+    assert_eq!(values.next(), Some(0));
+    let mut indices = (0..5_u8).reiterate().map_indices(|i| i + 100);
+    assert_eq!(indices.at(4), Some(104));
+    assert_eq!(indices.at(5), None);
+    let mut both = (0..5_u8)
+        .reiterate()
+        .map(|indexed| (indexed.index, *indexed.value));
+    assert_eq!(both.at(2), Some((2, 2)));
+    assert_eq!(both.index(), 0);
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn assert_reiter_eq_reports_context() {
+    let mut iter = (0..6_u8).reiterate();
+    crate::assert_reiter_eq!(iter, 0..6);
+    let message = crate::testing::mismatch(&mut iter, [0, 1, 2, 9]).unwrap_or_default();
+    assert!(message.starts_with(
+        "Reiterator differs from expected at index 3: found Some(3), expected Some(9)"
+    ));
+    assert!(message.contains("> [3] 3"));
+    assert!(message.contains("  [5] 5"));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn filter_runs_predicate_once() {
+    let mut calls = 0_u32;
+    let mut evens = (0..10_u8).reiterate().filter(|&i| {
+        calls += 1;
+        i % 2 == 0
+    });
+    assert_eq!(evens.at(2), Some(&4));
+    assert_eq!(evens.source_index(2), Some(4));
+    while evens.next().is_some() {}
+    evens.restart();
+    while evens.next().is_some() {}
+    assert_eq!(evens.at(5), None);
+    drop(evens);
+    assert_eq!(calls, 10);
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn snapshot_round_trip() {
+    use crate::testing::{check_snapshot, SnapshotMismatch, SnapshotOutcome};
+    let mut stored: Option<::alloc::string::String> = None;
+    let mut iter = "ab".chars().reiterate();
+    assert_eq!(
+        check_snapshot(&mut iter, None, |fresh| stored = Some(fresh.into())),
+        Ok(SnapshotOutcome::Created),
+    );
+    assert_eq!(stored.as_deref(), Some("0: 'a'\n1: 'b'\n"));
+    assert_eq!(
+        check_snapshot(&mut iter, stored.as_deref(), |_| unreachable!()),
+        Ok(SnapshotOutcome::Matched),
+    );
+    assert_eq!(
+        check_snapshot(&mut iter, Some("0: 'a'\n1: 'c'\n"), |_| unreachable!()),
+        Err(SnapshotMismatch {
+            line: 1,
+            expected: "1: 'c'".into(),
+            found: "1: 'b'".into(),
+        }),
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn fold_and_try_fold() {
+    let mut iter = (1..=4_u32).reiterate();
+    assert_eq!(iter.fold(0, |sum, indexed| sum + indexed.value), 10);
+    iter.restart();
+    assert_eq!(
+        iter.try_fold(0, |sum, indexed| if *indexed.value < 3 {
+            Ok(sum + indexed.value)
+        } else {
+            Err(indexed.index)
+        }),
+        Err(2),
+    );
+    assert_eq!(iter.index, 3);
+    assert_eq!(
+        iter.try_fold(0, |sum, indexed| Ok::<_, ()>(sum + indexed.value)),
+        Ok(4)
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn take_and_take_while_cap_infinite_sources() {
+    let mut first = (0_u32..).reiterate().take(3);
+    assert_eq!(first.at(2), Some(&2));
+    assert_eq!(first.at(1_000_000), None);
+    while first.next().is_some() {}
+    first.restart();
+    assert_eq!(first.next().map(|indexed| *indexed.value), Some(0));
+    assert_eq!(first.into_inner().cached_len(), 3);
+
+    let mut calls = 0_u32;
+    let mut small = (0_u32..).reiterate().take_while(|&i| {
+        calls += 1;
+        i < 4
+    });
+    assert_eq!(small.at(3), Some(&3));
+    assert_eq!(small.at(4), None);
+    assert_eq!(small.at(1_000_000), None);
+    drop(small);
+    assert_eq!(calls, 5);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn probe_policies_bound_cursor_reads() {
+    use crate::cache::Probe;
+    let mut iter = (0_u32..).reiterate();
+    iter.set_probe(Probe::CacheOnly);
+    assert_eq!(iter.get(), None);
+    assert_eq!(iter.cached_len(), 0);
+    assert_eq!(iter.at(1), Some(&1)); // Random access is unaffected.
+    assert_eq!(iter.get().map(|indexed| *indexed.value), Some(0));
+    iter.set_probe(Probe::AtMost(3));
+    iter.index = 100;
+    assert_eq!(iter.get(), None);
+    assert_eq!(iter.cached_len(), 5);
+    iter.index = 7;
+    assert_eq!(iter.next().map(|indexed| *indexed.value), Some(7));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn skip_and_skip_while_rebase_indices() {
+    let mut skipped = "abcde".chars().reiterate().skip(2);
+    assert_eq!(skipped.at(0), Some(&'c'));
+    assert_eq!(skipped.next().map(|indexed| indexed.index), Some(0));
+    assert_eq!(skipped.at(3), None);
+
+    let mut calls = 0_u32;
+    let mut trimmed = "  hi".chars().reiterate().skip_while(|&c| {
+        calls += 1;
+        c == ' '
+    });
+    assert_eq!(trimmed.at(0), Some(&'h'));
+    assert_eq!(trimmed.next().map(|indexed| *indexed.value), Some('h'));
+    trimmed.restart();
+    assert_eq!(trimmed.next().map(|indexed| *indexed.value), Some('h'));
+    assert_eq!(trimmed.offset(), 2);
+    drop(trimmed);
+    assert_eq!(calls, 3);
+}
+
+#[test]
+fn out_of_bounds_reads_stop_pulling_once_the_source_ends() {
+    let mut pulls = 0_u8;
+    let mut iter = core::iter::from_fn(|| {
+        pulls += 1;
+        (pulls <= 3).then_some(pulls)
+    })
+    .reiterate();
+    assert_eq!(iter.at(5), None);
+    assert_eq!(iter.at(5), None);
+    assert_eq!(iter.at(9), None);
+    #[cfg(feature = "unstable")]
+    assert_eq!(iter.cache.known_len(), Some(3));
+    drop(iter);
+    assert_eq!(pulls, 4);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+#[allow(clippy::unwrap_used)]
+fn step_by_strides_the_cursor_only() {
+    let mut iter = (0_u8..10)
+        .reiterate()
+        .step_by(core::num::NonZeroUsize::new(3).unwrap());
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.next().map(|indexed| *indexed.value), Some(0));
+    assert_eq!(iter.next().map(|indexed| *indexed.value), Some(3));
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.at(4), Some(&4));
+    let rest: Vec<_> = iter.map_values(|&value| value).collect();
+    assert_eq!(rest, [6, 9]);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn dyn_send_reiterator_crosses_threads() {
+    use crate::dynamic::{reiterate_dyn, reiterate_dyn_send};
+    let not_send = std::rc::Rc::new(3_u8);
+    let mut local = reiterate_dyn(core::iter::repeat_with(move || *not_send).take(1));
+    assert_eq!(local.at(0), Some(&3));
+    let mut shared = reiterate_dyn_send((0_u8..4).map(|i| i * 2));
+    assert_eq!(shared.at(1), Some(&2));
+    let sum = std::thread::spawn(move || {
+        shared.restart();
+        shared.map_values(|&value| value).sum::<u8>()
+    })
+    .join()
+    .ok();
+    assert_eq!(sum, Some(12));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn chain_spans_both_caches() {
+    let mut chained = "ab".chars().reiterate().chain("cd".chars().reiterate());
+    assert_eq!(chained.at(1), Some(&'b'));
+    assert_eq!(chained.at(3), Some(&'d'));
+    assert_eq!(chained.at(4), None);
+    assert_eq!(chained.next().map(|indexed| *indexed.value), Some('a'));
+    chained.index = 2;
+    assert_eq!(chained.get().map(|indexed| *indexed.value), Some('c'));
+    let (mut first, mut second) = chained.into_inner();
+    assert_eq!(first.at(0), Some(&'a'));
+    assert_eq!(second.at(0), Some(&'c'));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn rc_values_outlive_borrows() {
+    use crate::rc::reiterate_rc;
+    use ::alloc::rc::Rc;
+    let mut tokens = reiterate_rc(["let", "x", "=", "1"]);
+    let nodes: Vec<_> = tokens
+        .by_ref()
+        .filter(|&(index, _)| index % 2 == 1)
+        .collect();
+    tokens.restart();
+    let again = tokens.at(1);
+    assert_eq!(nodes.first().map(|(_, token)| **token), Some("x"));
+    assert!(again.is_some_and(|token| nodes
+        .first()
+        .is_some_and(|(_, node)| Rc::ptr_eq(node, &token))));
+    assert_eq!(tokens.next().map(|(index, _)| index), Some(0));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn zip_ends_at_the_shorter_source() {
+    let mut zipped = "abc".chars().reiterate().zip((0_u8..2).reiterate());
+    assert_eq!(zipped.next(), Some((0, (&'a', &0))));
+    assert_eq!(zipped.next(), Some((1, (&'b', &1))));
+    assert_eq!(zipped.next(), None);
+    assert_eq!(zipped.at(0), Some((&'a', &0)));
+    let (mut letters, mut numbers) = zipped.into_inner();
+    assert_eq!(letters.at(2), Some(&'c'));
+    assert_eq!(numbers.at(1), Some(&1));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn element_ids_resolve_until_evicted() {
+    let mut iter = "abc".chars().reiterate();
+    let b = iter.id(1);
+    assert_eq!(iter.id(3), None);
+    assert_eq!(b.map(|id| id.index()), Some(1));
+    assert_eq!(b.and_then(|id| iter.resolve(id)), Some(&'b'));
+    iter.retain_cached(|&c| c != 'b');
+    assert_eq!(b.and_then(|id| iter.resolve(id)), None);
+    assert_eq!(iter.id(1), None);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn peek_ahead_leaves_the_cursor_alone() {
+    let mut iter = "abc".chars().reiterate();
+    assert_eq!(iter.next().map(|indexed| *indexed.value), Some('a'));
+    assert_eq!(iter.peek().map(|indexed| *indexed.value), Some('b'));
+    assert_eq!(iter.peek_ahead(1).map(|indexed| indexed.index), Some(2));
+    assert_eq!(iter.peek_ahead(2), None);
+    assert_eq!(iter.peek_ahead(usize::MAX), None);
+    assert_eq!(iter.index, 1);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn resolve_many_reports_stale_ids() {
+    let mut iter = (0_u8..4).reiterate();
+    let ids: Vec<_> = (0..4).filter_map(|index| iter.id(index)).collect();
+    iter.retain_cached(|&value| value % 2 == 0);
+    let resolved = iter.resolve_many(&ids);
+    assert_eq!(
+        resolved
+            .live
+            .iter()
+            .map(|&(_, &value)| value)
+            .collect::<Vec<_>>(),
+        [0, 2]
+    );
+    assert_eq!(
+        resolved
+            .stale
+            .iter()
+            .map(|id| id.index())
+            .collect::<Vec<_>>(),
+        [1, 3]
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn prev_walks_backwards() {
+    let mut iter = "abc".chars().reiterate();
+    assert_eq!(iter.prev(), None);
+    assert_eq!(iter.index, 0);
+    iter.index = 3;
+    assert_eq!(iter.prev().map(|indexed| *indexed.value), Some('c'));
+    assert_eq!(iter.at(0), Some(&'a'));
+    iter.retain_cached(|&c| c != 'b');
+    assert_eq!(iter.prev().map(|indexed| indexed.index), Some(0));
+    assert_eq!(iter.step_back(), None);
+    assert_eq!(iter.next().map(|indexed| *indexed.value), Some('a'));
+    assert_eq!(iter.prev().map(|indexed| *indexed.value), Some('a'));
+}
+
+#[cfg(feature = "cursor-history")]
+#[test]
+fn cursor_history_shows_how_we_got_here() {
+    use crate::cursor::CursorEvent;
+    let mut iter = "abcd".chars().reiterate();
+    let _ = iter.next();
+    iter.record_cursor_history(4);
+    let _ = iter.next();
+    iter.index = 3;
+    let _ = iter.get();
+    let _ = iter.prev();
+    let _ = iter.peek_ahead(1);
+    assert_eq!(
+        iter.cursor_history().collect::<Vec<_>>(),
+        [
+            CursorEvent::Assigned(3),
+            CursorEvent::Get(3),
+            CursorEvent::Prev(3),
+            CursorEvent::PeekAhead(2),
+        ]
+    );
+}
+
+#[cfg(feature = "cursor-history")]
+#[test]
+fn cursor_history_trusts_every_method_that_moves() {
+    use crate::cursor::CursorEvent;
+    let mut iter = (0_u8..10).reiterate();
+    iter.record_cursor_history(8);
+    let _ = iter.seek_relative(3);
+    let _ = iter.peek();
+    let _ = iter.advance_by(2);
+    let _ = iter.next();
+    let _ = iter.step_back();
+    let _ = iter.lazy_next();
+    let _ = iter.get();
+    assert_eq!(
+        iter.cursor_history().collect::<Vec<_>>(),
+        [
+            CursorEvent::Get(3),
+            CursorEvent::Next(5),
+            CursorEvent::Get(6),
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+#[allow(clippy::unwrap_used)]
+fn seek_relative_and_advance_by() {
+    let mut iter = (0_u8..5).reiterate();
+    assert_eq!(iter.seek_relative(-1), None);
+    assert_eq!(iter.seek_relative(3), Some(3));
+    assert_eq!(iter.seek_relative(-2), Some(1));
+    assert_eq!(iter.cached_len(), 0);
+    assert_eq!(iter.advance_by(2), Ok(()));
+    assert_eq!(iter.index, 3);
+    assert_eq!(iter.cached_len(), 3);
+    assert_eq!(iter.advance_by(0), Ok(()));
+    assert_eq!(
+        iter.advance_by(5),
+        Err(core::num::NonZeroUsize::new(3).unwrap())
+    );
+    assert_eq!(iter.index, 5);
+    assert_eq!(iter.seek_relative(isize::MIN), None);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn nth_keeps_what_it_skips() {
+    let mut iter = "abcde".chars().reiterate();
+    assert_eq!(iter.nth(2).map(|indexed| *indexed.value), Some('c'));
+    assert_eq!(iter.cached_len(), 3);
+    assert_eq!(iter.nth(0).map(|indexed| *indexed.value), Some('d'));
+    assert_eq!(iter.nth(1), None);
+    iter.restart();
+    assert_eq!(iter.nth(1).map(|indexed| *indexed.value), Some('b'));
+
+    let mut std_iter = "abcde".chars();
+    assert_eq!(std_iter.nth(2), Some('c'));
+    assert_eq!(std_iter.next(), Some('d')); // `a` and `b` are gone for good.
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn stream_state_tracks_progress() {
+    use crate::cache::{Probe, StreamState};
+    let mut iter = (0_u8..3).reiterate();
+    assert_eq!(iter.state(), StreamState::Unstarted);
+    assert_eq!(iter.at(0), Some(&0));
+    assert_eq!(iter.state(), StreamState::Partial { cached: 1 });
+    iter.set_probe(Probe::CacheOnly);
+    iter.index = 2;
+    assert_eq!(iter.get(), None);
+    assert_eq!(iter.state(), StreamState::Budgeted { cached: 1 });
+    assert_eq!(iter.at(3), None);
+    assert_eq!(iter.state(), StreamState::Exhausted { len: 3 });
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn cycle_wraps_forever() {
+    let mut iter = "abc".chars().reiterate();
+    let looped: String = iter
+        .cycle()
+        .unwrap()
+        .take(7)
+        .map(|indexed| *indexed.value)
+        .collect();
+    assert_eq!(looped, "abcabca");
+    iter.retain_cached(|&c| c != 'b');
+    assert_eq!(
+        iter.cycle()
+            .unwrap()
+            .take(3)
+            .map(|indexed| indexed.index)
+            .collect::<Vec<_>>(),
+        [0, 2, 0]
+    );
+    iter.retain_cached(|_| false);
+    assert_eq!(iter.cycle().unwrap().next(), None);
+    assert_eq!(
+        "".chars().reiterate().cycle().unwrap().size_hint(),
+        (0, Some(0))
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn by_ref_lends_the_cursor() {
+    fn first_two<'a>(iter: impl Iterator<Item = crate::indexed::Indexed<'a, char>>) -> String {
+        iter.take(2).map(|indexed| *indexed.value).collect()
+    }
+    let mut iter = "abcd".chars().reiterate();
+    assert_eq!(first_two(iter.by_ref()), "ab");
+    assert_eq!(iter.index, 2);
+    let held: Vec<_> = iter.by_ref().collect();
+    assert_eq!(held.len(), 2);
+    assert_eq!(held.first().map(|indexed| *indexed.value), Some('c'));
+    iter.restart();
+    assert_eq!(iter.next().map(|indexed| *indexed.value), Some('a'));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn by_ref_holds_off_the_window() {
+    use crate::{cache::Evicted, Reiterator};
+    let mut iter = Reiterator::builder(0_u64..)
+        .window(core::num::NonZeroUsize::new(2).unwrap())
+        .build();
+    let held: Vec<_> = iter.by_ref().take(5).collect();
+    assert_eq!(
+        held.iter()
+            .map(|indexed| *indexed.value)
+            .collect::<Vec<_>>(),
+        [0, 1, 2, 3, 4]
+    );
+    drop(held);
+    assert_eq!(iter.cached_iter().count(), 5);
+    assert_eq!(iter.at(5), Some(&5));
+    assert_eq!(iter.at_or_evicted(3), Err(Evicted { index: 3 }));
+    assert_eq!(iter.cached_iter().count(), 2);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn by_ref_holds_off_overflow_eviction() {
+    use crate::{cache::Overflow, Reiterator};
+    let mut iter = Reiterator::builder(0_u64..)
+        .max_cached(3, Overflow::Evict)
+        .build();
+    let held: Vec<_> = iter.by_ref().take(5).map(|indexed| indexed.value).collect();
+    assert_eq!(held, [&0, &1, &2]);
+    assert_eq!(iter.index, 4);
+    assert_eq!(iter.at(5), Some(&5));
+    assert_eq!(iter.at(0), None);
+    assert_eq!(iter.cached_iter().count(), 3);
+}
+
+#[test]
+#[cfg(feature = "profiling")]
+fn by_ref_holds_off_auto_tuning() {
+    let mut iter = (0_u32..100).reiterate();
+    iter.auto_tune(4);
+    let held: Vec<_> = iter
+        .by_ref()
+        .take(20)
+        .map(|indexed| indexed.value)
+        .collect();
+    assert!(held.iter().map(|&&value| value).eq(0..20));
+    assert!(iter.tuning().is_some());
+    assert_eq!(iter.cached_iter().count(), 20);
+    assert_eq!(iter.at(30), Some(&30));
+    assert_eq!(iter.at(10), None);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn count_last_and_exhaust_keep_everything() {
+    let mut iter = (0_u8..5).reiterate();
+    assert_eq!(iter.next().map(|indexed| *indexed.value), Some(0));
+    assert_eq!(iter.count(), Ok(4));
+    assert_eq!(iter.index, 1);
+    assert_eq!(iter.last().unwrap().map(|indexed| *indexed.value), Some(4));
+    iter.retain_cached(|&value| value < 3);
+    assert_eq!(iter.last().unwrap().map(|indexed| indexed.index), Some(2));
+    assert_eq!(iter.count(), Ok(2));
+    assert_eq!(iter.exhaust(), Ok(5));
+    assert_eq!(iter.at(0), Some(&0));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn growth_strategies_bound_capacity() {
+    use crate::cache::{Cached, Growth};
+    let mut exact = (0_u16..100).cached();
+    exact.set_growth(Growth::Exact);
+    assert_eq!(exact.exhaust(), Ok(100));
+    assert_eq!(exact.capacity(), 100);
+    let mut chunked = (0_u16..101).cached();
+    chunked.set_growth(Growth::Chunk(25));
+    assert_eq!(chunked.exhaust(), Ok(101));
+    assert_eq!(chunked.capacity(), 125);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn find_and_position_memoize() {
+    use crate::find::Finder;
+    let mut iter = "a,b,,c".chars().reiterate();
+    assert_eq!(iter.position(|&c| c == ','), Some(1));
+    assert_eq!(
+        iter.find(|&c| c == ',').map(|indexed| indexed.index),
+        Some(3)
+    );
+    assert_eq!(iter.position(|&c| c == 'z'), None);
+    assert_eq!(iter.index, 6);
+
+    let mut calls = 0_u32;
+    let mut commas = Finder::new(|&c: &char| {
+        calls += 1;
+        c == ','
+    });
+    iter.restart();
+    while iter.position_memo(&mut commas).is_some() {}
+    iter.restart();
+    assert_eq!(
+        iter.find_memo(&mut commas).map(|indexed| *indexed.value),
+        Some(',')
+    );
+    assert_eq!(iter.position_memo(&mut commas), Some(3));
+    assert_eq!(commas.matches(), [1, 3, 4]);
+    drop(commas);
+    assert_eq!(calls, 6);
+}
+
+#[cfg(feature = "address-audit")]
+#[test]
+fn addresses_survive_growth_and_eviction() {
+    // Every access audits the whole cache, so each read below is also a check.
+    let mut iter = (0_u32..1_000).reiterate();
+    let first: *const u32 = iter.at(0).map_or(core::ptr::null(), |value| value);
+    assert_eq!(iter.at(999), Some(&999));
+    iter.retain_cached(|&value| value % 3 != 0 || value == 0);
+    assert_eq!(iter.at(0).map(|value| -> *const u32 { value }), Some(first));
+}
+
+#[cfg(feature = "address-audit")]
+#[test]
+#[should_panic(expected = "Address audit failed: cached element #1 moved")]
+fn address_audit_catches_a_moved_element() {
+    use ::alloc::{boxed::Box, collections::BTreeMap};
+    let mut audit = crate::audit::AddressAudit::new();
+    let before = [Some(Box::new(0_u8)), Some(Box::new(1))];
+    audit.verify(&before, &BTreeMap::new());
+    let [kept, _] = before;
+    let after = [kept, Some(Box::new(1))];
+    audit.verify(&after, &BTreeMap::new());
+}
+
+#[cfg(feature = "address-audit")]
+#[test]
+fn address_audit_forgets_evicted_and_truncated_elements() {
+    use ::alloc::{boxed::Box, collections::BTreeMap};
+    let mut audit = crate::audit::AddressAudit::new();
+    let first = [Some(Box::new(0_u8)), Some(Box::new(1))];
+    audit.verify(&first, &BTreeMap::new());
+    let evicted: [Option<Box<u8>>; 2] = [None, None];
+    audit.verify(&evicted, &BTreeMap::new());
+    let recomputed = [Some(Box::new(0_u8))];
+    audit.verify(&recomputed, &BTreeMap::new());
+    audit.verify(&recomputed, &BTreeMap::new());
+}
+
+#[cfg(feature = "address-audit")]
+#[test]
+#[should_panic(expected = "Address audit failed: seeded element #3 moved")]
+fn address_audit_catches_a_moved_seeded_element() {
+    use ::alloc::{boxed::Box, collections::BTreeMap};
+    let mut audit = crate::audit::AddressAudit::new();
+    let slots: [Option<Box<u8>>; 0] = [];
+    let before = BTreeMap::from([(3, Box::new(3_u8))]);
+    audit.verify(&slots, &before);
+    let after = BTreeMap::from([(3, Box::new(3_u8))]);
+    audit.verify(&slots, &after);
+}
+
+#[cfg(feature = "address-audit")]
+#[test]
+#[should_panic(expected = "Address audit failed: cached element #0 moved")]
+fn address_audit_catches_a_seeded_element_cached_elsewhere() {
+    use ::alloc::{boxed::Box, collections::BTreeMap};
+    let mut audit = crate::audit::AddressAudit::new();
+    let seeded = BTreeMap::from([(0, Box::new(0_u8))]);
+    audit.verify(&[], &seeded);
+    let cached = [Some(Box::new(0_u8))];
+    audit.verify(&cached, &BTreeMap::new());
+}
+
+#[cfg(feature = "address-audit")]
+#[test]
+fn seeded_elements_stay_put_once_cached() {
+    use crate::cache::Cache;
+    let mut cache = Cache::with_segments([(3..5, vec![30_u32, 40]), (7..8, vec![70])], 0_u32..10);
+    let seeded = cache.get(4).map(|value| -> *const u32 { value });
+    assert_eq!(cache.push_front_of_source(99), Ok(()));
+    assert_eq!(cache.get(9), Some(&8));
+    assert_eq!(cache.get(5).map(|value| -> *const u32 { value }), seeded);
+}
+
+#[cfg(feature = "address-audit")]
+#[test]
+fn address_audit_holds_across_clones_and_back_pulls() {
+    let mut iter = (0_u32..100).reiterate();
+    assert_eq!(iter.at(10), Some(&10));
+    let mut clone = iter.clone();
+    assert_eq!(clone.at(50), Some(&50));
+    assert_eq!(iter.at_back(0), Some(&99));
+    assert_eq!(iter.at(99), Some(&99));
+    assert_eq!(iter.at(0), Some(&0));
+    assert_eq!(clone.at(0), Some(&0));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn at_range_yields_a_window() {
+    let mut iter = "abcdef".chars().reiterate();
+    assert_eq!(
+        iter.at_range(1..4)
+            .map(|indexed| *indexed.value)
+            .collect::<String>(),
+        "bcd"
+    );
+    assert_eq!(iter.cached_len(), 4);
+    assert_eq!(
+        iter.at_range(4..10)
+            .map(|indexed| indexed.index)
+            .collect::<Vec<_>>(),
+        [4, 5]
+    );
+    assert_eq!(iter.at_range(3..2).count(), 0);
+    let mut cache = "xyz".chars().cached();
+    assert_eq!(
+        cache
+            .get_range(0..2)
+            .map(|indexed| *indexed.value)
+            .collect::<String>(),
+        "xy"
+    );
+    assert_eq!(cache.cached_len(), 2);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn at_many_keeps_input_order() {
+    let mut iter = "abcde".chars().reiterate();
+    assert_eq!(
+        iter.at_many(&[3, 0, 9, 3]),
+        [Some(&'d'), Some(&'a'), None, Some(&'d')]
+    );
+    assert_eq!(iter.cached_len(), 5);
+    assert!(iter.at_many(&[]).is_empty());
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn sealed_cursor_only_moves_through_checked_paths() {
+    let mut sealed = "abc".chars().reiterate().seal();
+    assert_eq!(sealed.seek(2).map(|indexed| *indexed.value), Some('c'));
+    assert!(sealed.seek(3).is_none());
+    assert_eq!(sealed.index(), 2);
+    assert!(sealed.seek_relative(-3).is_none());
+    assert_eq!(
+        sealed.seek_relative(-2).map(|indexed| indexed.index),
+        Some(0)
+    );
+    assert_eq!(sealed.next().map(|indexed| *indexed.value), Some('a'));
+    assert_eq!(sealed.cached_len(), 3);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn convert_into_vec_with_and_without_budget() {
+    use crate::convert::OverBudget;
+    let mut iter = (0_u8..5).reiterate();
+    assert_eq!(iter.at(1), Some(&1));
+    iter.retain_cached(|&value| value != 0);
+    assert_eq!(Vec::from(iter), [1, 2, 3, 4]);
+    assert_eq!(
+        Vec::try_from((0_u8..5).reiterate().with_budget(5)),
+        Ok(vec![0, 1, 2, 3, 4])
+    );
+    assert_eq!(
+        Vec::try_from((0_u8..).reiterate().with_budget(3)),
+        Err(OverBudget {
+            prefix: vec![0, 1, 2],
+            budget: 3
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn exhaustion_is_observable() {
+    let mut iter = (0_u8..3).reiterate();
+    assert!(!iter.is_exhausted());
+    assert_eq!(iter.at(2), Some(&2));
+    assert_eq!((iter.cached_len(), iter.known_len()), (3, None));
+    assert_eq!(iter.at(3), None);
+    assert!(iter.is_exhausted());
+    assert_eq!(iter.known_len(), Some(3));
+}
+
+#[cfg(all(feature = "std", feature = "unstable"))]
+#[test]
+fn populate_catching_survives_a_panicking_source() {
+    use crate::cache::StreamState;
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let mut iter = (0_u8..10)
+        .map(|value| {
+            assert!(value != 3, "three is right out");
+            value
+        })
+        .reiterate();
+    assert_eq!(iter.populate_catching(2).ok(), Some(2));
+    let panicked = iter.populate_catching(5).err();
+    std::panic::set_hook(hook);
+    assert_eq!(panicked.as_ref().map(|panicked| panicked.index), Some(3));
+    assert_eq!(
+        panicked.as_ref().and_then(|panicked| panicked.message()),
+        Some("three is right out")
+    );
+    assert_eq!(iter.state(), StreamState::Poisoned { cached: 3 });
+    assert_eq!(iter.at(2), Some(&2));
+    assert_eq!(iter.at(4), None);
+    assert_eq!(iter.populate_catching(5).ok(), Some(3));
+}
+
+/// Tiny deterministic RNG (xorshift) so tests don't need a seeded generator from `rand` itself.
+#[cfg(feature = "rand")]
+struct XorShift(u64);
+
+#[cfg(feature = "rand")]
+impl rand::RngCore for XorShift {
+    fn next_u32(&mut self) -> u32 {
+        #[allow(clippy::cast_possible_truncation, clippy::as_conversions)]
+        let truncated = (self.next_u64() >> 32) as u32;
+        truncated
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand::rand_core::impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn sample_indices_dense_and_sparse() {
+    use crate::sample::SampleMode;
+    let mut dense = (0_u32..).reiterate();
+    let sample: Vec<_> = dense
+        .sample_indices(&mut XorShift(42), 100, 5, SampleMode::Dense)
+        .into_iter()
+        .map(|indexed| indexed.index)
+        .collect();
+    assert_eq!(sample.len(), 5);
+    assert!(sample.windows(2).all(|pair| pair.first() < pair.last()));
+    assert!(sample.iter().all(|&index| index < 100));
+    assert_eq!(dense.at_range(0..100).count(), 100);
+
+    let mut sparse = (0_u32..).reiterate();
+    let again: Vec<_> = sparse
+        .sample_indices(&mut XorShift(42), 100, 5, SampleMode::Sparse)
+        .into_iter()
+        .map(|indexed| *indexed.value)
+        .collect();
+    assert_eq!(
+        again.into_iter().map(usize::try_from).collect::<Vec<_>>(),
+        sample.into_iter().map(Ok).collect::<Vec<_>>()
+    );
+    assert_eq!(sparse.at_range(0..100).count(), 5);
+
+    let mut short = (0_u32..3).reiterate();
+    assert_eq!(
+        short
+            .sample_indices(&mut XorShift(7), 100, 5, SampleMode::Dense)
+            .len(),
+        3
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn into_vec_moves_without_cloning() {
+    struct NotClone(u8);
+    let mut iter = (0_u8..3).map(NotClone).reiterate();
+    assert_eq!(iter.at(0).map(|value| value.0), Some(0));
+    let owned = iter.into_vec();
+    assert_eq!(
+        owned.iter().map(|value| value.0).collect::<Vec<_>>(),
+        [0, 1, 2]
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn reservoir_sample_keeps_only_winners() {
+    let mut iter = (0_u32..).reiterate();
+    let sample: Vec<_> = iter
+        .reservoir_sample(4, &mut XorShift(9), 1_000)
+        .into_iter()
+        .map(|indexed| *indexed.value)
+        .collect();
+    assert_eq!(sample.len(), 4);
+    assert!(sample.windows(2).all(|pair| pair.first() < pair.last()));
+    assert_eq!(iter.cached_len(), 1_000);
+    assert_eq!(iter.at_range(0..1_000).count(), 4);
+    assert_eq!(iter.reservoir_sample(10, &mut XorShift(9), 1_000).len(), 4);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn cached_iter_never_drives_the_source() {
+    let mut iter = (0_u64..).reiterate();
+    assert_eq!(iter.cached_iter().count(), 0);
+    assert_eq!(iter.at(2), Some(&2));
+    iter.retain_cached(|&value| value != 1);
+    assert_eq!(
+        iter.cached_iter()
+            .map(|indexed| *indexed.value)
+            .collect::<Vec<_>>(),
+        [0, 2]
+    );
+    assert_eq!(iter.cached_len(), 3);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+#[allow(clippy::unwrap_used)]
+fn auto_checkpoint_and_warm_resume() {
+    use crate::Reiterator;
+    use std::sync::{Arc, Mutex};
+    let saved = Arc::new(Mutex::new(Vec::new()));
+    let mut iter = (0_u32..).map(|value| value * 10).reiterate();
+    assert_eq!(iter.at(1), Some(&10));
+    let sink = Arc::clone(&saved);
+    iter.auto_checkpoint(2, move |block| {
+        sink.lock()
+            .unwrap()
+            .extend(block.map(|indexed| *indexed.value));
+    });
+    assert_eq!(*saved.lock().unwrap(), [0, 10]);
+    assert_eq!(iter.at(4), Some(&40));
+    drop(iter); // Crash!
+
+    let prefix = saved.lock().unwrap().clone();
+    assert_eq!(prefix, [0, 10, 20, 30]);
+    let resume_at = u32::try_from(prefix.len()).unwrap();
+    let mut resumed = Reiterator::with_warm_cache(prefix, (resume_at..).map(|value| value * 10));
+    assert_eq!(resumed.cached_len(), 4);
+    assert_eq!(resumed.at(4), Some(&40));
+    assert_eq!(resumed.at(1), Some(&10));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn replace_and_reset_source() {
+    let mut iter = vec![1_u8, 2].into_iter().reiterate();
+    let first = iter.id(0);
+    assert_eq!(iter.at(2), None);
+    let _ = iter.replace_source(vec![3, 4]);
+    assert_eq!(iter.at(2), Some(&3));
+    assert_eq!(iter.collect_all::<Vec<_>>(), [1, 2, 3, 4]);
+    assert_eq!(first.and_then(|id| iter.resolve(id)), Some(&1));
+    let _ = iter.reset_source(vec![9]);
+    assert_eq!(iter.cached_len(), 0);
+    assert_eq!(iter.at(0), Some(&9));
+    assert_eq!(first.and_then(|id| iter.resolve(id)), None);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn gap_tolerant_resume_fills_only_the_gaps() {
+    use crate::Reiterator;
+    let mut iter = Reiterator::with_segments(
+        [(5..8, vec![50, 60, 70]), (0..2, vec![0, 10])],
+        (0_u32..8).map(|value| value * 10),
+    );
+    assert_eq!(iter.at(6), Some(&60));
+    assert_eq!(iter.cached_len(), 0);
+    assert_eq!(iter.at(3), Some(&30));
+    assert_eq!(
+        iter.collect_all::<Vec<_>>(),
+        [0, 10, 20, 30, 40, 50, 60, 70]
+    );
+    assert_eq!(iter.known_len(), Some(8));
+    let mut resumed = Reiterator::with_segments([(1..3, [100_u8, 200])], 0_u8..5);
+    assert_eq!(resumed.collect_all::<Vec<_>>(), [0, 100, 200, 3, 4]);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn into_parts_and_back() {
+    use crate::Reiterator;
+    let mut iter = (0_u8..5).reiterate();
+    assert_eq!(iter.next().map(|indexed| *indexed.value), Some(0));
+    assert_eq!(iter.at(2), Some(&2));
+    let Ok((cached, source, index)) = iter.into_parts() else {
+        panic!("nothing was evicted");
+    };
+    assert_eq!((cached.as_slice(), index), ([0, 1, 2].as_slice(), 1));
+    let mut resumed = Reiterator::from_parts(cached, source, index);
+    assert_eq!(resumed.next().map(|indexed| *indexed.value), Some(1));
+    assert_eq!(resumed.at(4), Some(&4));
+    resumed.retain_cached(|&value| value != 3);
+    assert_eq!(
+        resumed.into_parts().err().map(|(evicted, _)| evicted.index),
+        Some(3)
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn truncate_recomputes_the_tail() {
+    let mut iter = vec![1_u8, 2, 99, 99].into_iter().reiterate();
+    assert_eq!(iter.at(3), Some(&99));
+    let kept = iter.id(1);
+    let dropped = iter.id(2);
+    let _ = iter.truncate(2, vec![3, 4]);
+    assert_eq!(iter.cached_len(), 2);
+    assert_eq!(kept.and_then(|id| iter.resolve(id)), Some(&2));
+    assert_eq!(dropped.and_then(|id| iter.resolve(id)), None);
+    assert_eq!(iter.collect_all::<Vec<_>>(), [1, 2, 3, 4]);
+    assert_eq!(dropped.and_then(|id| iter.resolve(id)), None);
+    let _ = iter.clear(vec![7]);
+    assert_eq!(kept.and_then(|id| iter.resolve(id)), None);
+    assert_eq!(iter.collect_all::<Vec<_>>(), [7]);
+}
+
+#[test]
+#[cfg(all(feature = "shm", unix))]
+#[allow(clippy::unwrap_used)]
+fn shared_frozen_round_trip() {
+    use crate::shared::SharedFrozen;
+    let name = format!("/reiterator-test-{}", std::process::id());
+    let frozen = (0_u32..100).reiterate().freeze().unwrap();
+    let owner = frozen.share(&name).unwrap();
+    assert!(frozen.share(&name).is_err()); // already taken
+    let reader = SharedFrozen::<u32>::open_shared(&name).unwrap();
+    assert_eq!(*reader, *frozen);
+    assert_eq!(reader.to_frozen(), frozen);
+    assert!(SharedFrozen::<u64>::open_shared(&name).is_err()); // element size mismatch
+    drop(owner);
+    assert!(SharedFrozen::<u32>::open_shared(&name).is_err()); // unlinked
+    assert_eq!(reader.len(), 100); // but still mapped
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn capacity_control() {
+    let mut iter = crate::Reiterator::with_capacity(0_u8..10, 10);
+    assert!(iter.capacity() >= 10);
+    assert_eq!(iter.count(), Ok(10));
+    assert!(iter.capacity() >= 10);
+    let _ = iter.truncate(2, 2..4);
+    iter.shrink_to_fit();
+    assert!(iter.capacity() < 10);
+    iter.reserve(2);
+    assert!(iter.capacity() >= 4);
+    assert_eq!(iter.collect_all::<Vec<_>>(), [0, 1, 2, 3]);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+#[allow(clippy::unwrap_used)]
+fn checksums_reject_stale_data() {
+    use crate::{checksum::Fnv1a, frozen::Frozen};
+    let frozen = (0_u32..10).reiterate().freeze().unwrap();
+    let sum = frozen.checksum::<Fnv1a>();
+    assert_eq!(
+        Frozen::verified::<Fnv1a>(frozen.clone().into_vec(), sum),
+        Ok(frozen)
+    );
+    let stale = Frozen::<u32>::verified::<Fnv1a>((1..11).collect(), sum);
+    assert_eq!(stale.map_err(|e| e.expected), Err(sum));
+    let mut iter = (0_u32..10).reiterate();
+    assert_eq!(iter.at(4), Some(&4));
+    let sum = iter.checksum::<Fnv1a>().unwrap();
+    let resumed =
+        crate::Reiterator::with_verified_warm_cache::<Fnv1a, _>(vec![0, 1, 2, 3, 4], sum, 5..10);
+    assert_eq!(
+        resumed.unwrap().collect_all::<Vec<_>>(),
+        (0..10).collect::<Vec<_>>()
+    );
+    assert!(crate::Reiterator::with_verified_warm_cache::<Fnv1a, _>(
+        vec![0, 1, 2, 3, 5],
+        sum,
+        5..10
+    )
+    .is_err());
+}
+
+#[test]
+#[cfg(all(feature = "crc32", feature = "xxhash"))]
+fn checksum_backends_disagree() {
+    use crate::checksum::{checksum, Crc32, Fnv1a, XxHash64};
+    let items = [1_u64, 2, 3];
+    let sums = [
+        checksum::<Fnv1a, _>(&items),
+        checksum::<Crc32, _>(&items),
+        checksum::<XxHash64, _>(&items),
+    ];
+    assert!(sums[0] != sums[1] && sums[1] != sums[2] && sums[0] != sums[2]);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn push_ahead_of_source() {
+    let mut iter = vec![10_u8, 20].reiterate();
+    assert_eq!(iter.at(0), Some(&10));
+    assert_eq!(iter.push_front_of_source(15), Ok(()));
+    iter.extend([16, 17]);
+    assert_eq!(iter.cached_len(), 4);
+    assert_eq!(iter.collect_all::<Vec<_>>(), [10, 15, 16, 17, 20]);
+    assert_eq!(iter.push_front_of_source(30), Ok(()));
+    assert_eq!(iter.known_len(), Some(6));
+    assert_eq!(iter.at(5), Some(&30));
+    assert_eq!(iter.at(6), None);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+#[allow(clippy::unwrap_used)]
+fn snapshot_migrates_old_versions() {
+    use crate::snapshot::{Snapshot, SnapshotError};
+    // Version 1 stored `u8`s; version 2 stores `u16`s.
+    let mut old = (0_u8..5).reiterate();
+    assert_eq!(old.at(2), Some(&2));
+    let bytes = old.snapshot(1).unwrap().encode(|&item, out| out.push(item));
+    let decode = |bytes: &[u8]| Some(u16::from_le_bytes(bytes.try_into().ok()?));
+    let migrate = |version, bytes: &[u8]| (version == 1).then(|| u16::from(bytes[0]) * 100);
+    let loaded = Snapshot::decode(&bytes, 2, decode, migrate).unwrap();
+    assert_eq!(loaded.items, [0, 100, 200]);
+    let reencoded = loaded.encode(|item, out| out.extend_from_slice(&item.to_le_bytes()));
+    assert_eq!(
+        Snapshot::decode(&reencoded, 2, decode, |_, _| None),
+        Ok(loaded.clone())
+    );
+    assert_eq!(
+        Snapshot::decode(&reencoded, 1, |_| Some(0_u8), |_, _| None),
+        Err(SnapshotError::TooNew {
+            version: 2,
+            current: 1
+        }),
+    );
+    assert_eq!(
+        Snapshot::decode(&reencoded[..reencoded.len() - 1], 2, decode, migrate),
+        Err(SnapshotError::Truncated),
+    );
+    let mut resumed = crate::Reiterator::from_snapshot(loaded, vec![300, 400]);
+    assert_eq!(resumed.collect_all::<Vec<_>>(), [0, 100, 200, 300, 400]);
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn graphemes_with_byte_ranges() {
+    use crate::unicode::reiterate_graphemes;
+    let text = "e\u{301}a👨‍👩‍👧b";
+    let mut iter = reiterate_graphemes(text);
+    assert_eq!(iter.at(2).map(|g| g.range.clone()), Some(4..22));
+    assert_eq!(iter.cached_len(), 3);
+    assert_eq!(iter.at(0).map(|g| g.cluster), Some("e\u{301}"));
+    assert_eq!(iter.cluster_containing(1), Some(0));
+    assert_eq!(iter.cluster_containing(10), Some(2));
+    assert_eq!(iter.cluster_containing(22), Some(3));
+    assert_eq!(iter.cluster_containing(23), None);
+    assert_eq!(iter.collect_all::<Vec<_>>().len(), 4);
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn cluster_containing_respects_eviction_and_refusal() {
+    use crate::{cache::Overflow, unicode::reiterate_graphemes};
+    use core::num::NonZeroUsize;
+    let mut windowed = reiterate_graphemes("abcdef");
+    assert_eq!(windowed.at(3).map(|g| g.cluster), Some("d"));
+    windowed.set_window(NonZeroUsize::new(2));
+    assert_eq!(windowed.cluster_containing(1), None);
+    assert_eq!(windowed.cluster_containing(2), Some(2));
+    assert_eq!(windowed.cluster_containing(5), Some(5));
+    let mut capped = reiterate_graphemes("abcdef");
+    capped.set_max_cached(2, Overflow::Refuse);
+    assert_eq!(capped.cluster_containing(1), Some(1));
+    assert_eq!(capped.cluster_containing(4), None);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn memo_map_runs_once_per_element() {
+    let mut calls = 0_usize;
+    let mut iter = (1_u8..=3).reiterate().memo_map(|&x| {
+        calls += 1;
+        u16::from(x) * 10
+    });
+    assert_eq!(iter.at(2), Some(&30));
+    while iter.next().is_some() {}
+    iter.restart();
+    assert_eq!(iter.next().map(|indexed| *indexed.value), Some(10));
+    assert_eq!(iter.at(3), None);
+    drop(iter);
+    assert_eq!(calls, 3);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn matching_delimiters_lazily() {
+    use crate::delimiter::Delimiter;
+    let classify = |c: &char| match *c {
+        '(' => Delimiter::Open(0),
+        ')' => Delimiter::Close(0),
+        '[' => Delimiter::Open(1),
+        ']' => Delimiter::Close(1),
+        _ => Delimiter::Neither,
+    };
+    let mut iter = "(a[b])]c("
+        .chars()
+        .reiterate()
+        .matching_delimiters(classify);
+    assert_eq!(iter.matching(2), Some(4));
+    assert_eq!(iter.matching(4), Some(2));
+    assert_eq!(iter.matching(0), Some(5));
+    assert_eq!(iter.matching(6), None); // unmatched closer
+    assert_eq!(iter.matching(1), None); // not a delimiter
+    assert_eq!(iter.matching(8), None); // never closed
+    assert_eq!(iter.matching(9), None); // out of bounds
+    assert_eq!(iter.into_inner().cached_len(), 9);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn flat_map_over_lines() {
+    let mut iter = vec!["a b", "", "c d e"]
+        .reiterate()
+        .flat_map(|line| line.split_whitespace().collect::<Vec<_>>());
+    assert_eq!(iter.at(3), Some(&"d"));
+    assert_eq!(iter.locate(3), Some((2, 1)));
+    assert_eq!(iter.locate(1), Some((0, 1)));
+    assert_eq!(iter.start_of(1), Some(2));
+    assert_eq!(iter.start_of(2), Some(2));
+    assert_eq!(iter.start_of(3), None);
+    assert_eq!(iter.at(5), None);
+    let mut flat = vec![];
+    while let Some(indexed) = iter.next() {
+        flat.push(*indexed.value);
+    }
+    assert_eq!(flat, ["a", "b", "c", "d", "e"]);
+    assert_eq!(iter.into_inner().cached_len(), 3);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+#[allow(clippy::unwrap_used)]
+fn one_based_display() {
+    use crate::indexed::IndexBase;
+    let mut iter = vec!["fn", "main"].reiterate();
+    iter.index = 1;
+    let indexed = iter.get().unwrap();
+    assert_eq!(format!("{indexed}"), "1: main");
+    assert_eq!(
+        format!("{}", indexed.display_with(IndexBase::One)),
+        "2: main"
+    );
+    assert_eq!(IndexBase::One.index(2), Some(1));
+    assert_eq!(IndexBase::One.index(0), None);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn scan_restores_state_on_rewind() {
+    let mut calls = 0_usize;
+    let mut sums = (1_u32..=10).reiterate().scan(0_u32, |sum, &x| {
+        calls += 1;
+        *sum += x;
+        (*sum < 20).then_some(*sum)
+    });
+    assert_eq!(sums.at(3), Some(&10));
+    assert_eq!(sums.state_at(2), Some(&3));
+    assert_eq!(sums.at(1), Some(&3));
+    assert_eq!(sums.state_at(5), Some(&15));
+    assert_eq!(sums.at(5), None); // 21 stops the scan
+    sums.index = 2;
+    assert_eq!(sums.next().map(|indexed| *indexed.value), Some(6));
+    drop(sums);
+    assert_eq!(calls, 6);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn adaptive_growth_sizes_chunks_by_bytes() {
+    use crate::cache::Growth;
+    // Each `u64` costs a pointer-sized slot plus 8 bytes, so 64 bytes is 4 elements.
+    let mut iter = (0_u64..).reiterate();
+    iter.set_growth(Growth::Adaptive {
+        bytes_per_chunk: 64,
+    });
+    assert_eq!(iter.at(0), Some(&0));
+    assert_eq!(iter.capacity(), 4);
+    assert_eq!(iter.at(4), Some(&4));
+    assert_eq!(iter.capacity(), 8);
+    // Jumping far ahead grows by up to 8 chunks at a time, then just enough for the rest.
+    assert_eq!(iter.at(100), Some(&100));
+    assert_eq!(iter.capacity(), 8 + 32 + 32 + 29);
+    // Huge elements still get at least one slot per chunk.
+    let mut huge = core::iter::repeat([0_u8; 1024]).reiterate();
+    huge.set_growth(Growth::Adaptive {
+        bytes_per_chunk: 64,
+    });
+    assert!(huge.at(2).is_some());
+    assert_eq!(huge.capacity(), 3);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+#[allow(clippy::unwrap_used)]
+fn chunks_and_group_by() {
+    let mut chunks = (0_u8..7)
+        .reiterate()
+        .chunks(core::num::NonZeroUsize::new(3).unwrap());
+    assert_eq!(chunks.range(1), Some(3..6));
+    assert_eq!(chunks.into_inner().cached_len(), 6);
+    let mut chunks = (0_u8..7)
+        .reiterate()
+        .chunks(core::num::NonZeroUsize::new(3).unwrap());
+    assert_eq!(
+        chunks
+            .group(2)
+            .map(|group| group.map(|indexed| *indexed.value).collect::<Vec<_>>()),
+        Some(vec![6])
+    );
+    assert!(chunks.range(3).is_none());
+
+    let mut calls = 0_usize;
+    let mut groups = [1_u8, 1, 2, 3, 3, 3, 1]
+        .into_iter()
+        .reiterate()
+        .group_by(|&x| {
+            calls += 1;
+            x
+        });
+    assert_eq!(groups.key(1), Some(&2));
+    assert_eq!(groups.range(0), Some(0..2));
+    assert_eq!(groups.range(2), Some(3..6));
+    assert_eq!(groups.range(3), Some(6..7));
+    assert_eq!(groups.range(4), None);
+    assert_eq!(groups.group(2).map(Iterator::count), Some(3));
+    drop(groups);
+    assert_eq!(calls, 7);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn erase_keeps_cache_and_cursor() {
+    use crate::dynamic::{reiterate_erased, Erased};
+    let mut iter = (0_u8..5).map(|x| x * 2).reiterate();
+    assert_eq!(iter.at(2), Some(&4));
+    iter.index = 1;
+    let mut erased: crate::Reiterator<Erased<u8>> = iter.erase();
+    assert_eq!(erased.cached_len(), 3);
+    assert_eq!(erased.next().map(|indexed| *indexed.value), Some(2));
+    assert_eq!(erased.collect_all::<Vec<_>>(), [0, 2, 4, 6, 8]);
+    let mut same_type: crate::Reiterator<Erased<u8>> = reiterate_erased(vec![1, 2]);
+    assert_eq!(same_type.at(1), Some(&2));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn contiguous_windows() {
+    use crate::contiguous::reiterate_contiguous;
+    let mut iter = reiterate_contiguous(0_u8..6);
+    assert_eq!(iter.window(1, 3), Some(&[1, 2, 3][..]));
+    assert_eq!(iter.cached(), [0, 1, 2, 3]);
+    assert_eq!(iter.window(0, 2), Some(&[0, 1][..]));
+    assert_eq!(iter.window(4, 3), None);
+    assert_eq!(iter.next().map(|indexed| *indexed.value), Some(0));
+    let frozen = iter.freeze();
+    assert_eq!(frozen.window(4, 2), Some(&[4, 5][..]));
+    assert_eq!(frozen.window(5, 2), None);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn dedup_and_unique_remap_indices() {
+    let mut dedup = vec![1_u8, 1, 2, 2, 2, 1, 3].reiterate().dedup();
+    assert_eq!(dedup.at(2), Some(&1));
+    assert_eq!(dedup.source_index(2), Some(5));
+    assert_eq!(dedup.at(3), Some(&3));
+    assert_eq!(dedup.at(4), None);
+    let mut comparisons = 0_usize;
+    let mut unique = vec![3_u8, 1, 3, 2, 1].reiterate().unique_by_key(|&x| {
+        comparisons += 1;
+        x
+    });
+    let mut kept = vec![];
+    while let Some(indexed) = unique.next() {
+        kept.push(*indexed.value);
+    }
+    unique.restart();
+    assert_eq!(unique.next().map(|indexed| *indexed.value), Some(3));
+    assert_eq!(unique.source_index(2), Some(3));
+    drop(unique);
+    assert_eq!(kept, [3, 1, 2]);
+    assert_eq!(comparisons, 5);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn views_skip_evicted_elements() {
+    fn evens() -> crate::Reiterator<core::ops::Range<u8>> {
+        let mut iter = (0_u8..6).reiterate();
+        assert_eq!(iter.at(5), Some(&5));
+        iter.retain_cached(|&value| value % 2 == 0);
+        iter
+    }
+
+    let mut take = evens().take(4);
+    let mut seen = vec![];
+    while let Some(indexed) = take.next() {
+        seen.push((indexed.index, *indexed.value));
+    }
+    assert_eq!(seen, [(0, 0), (2, 2)]);
+
+    let mut take_while = evens().take_while(|&value| value < 4);
+    let mut seen = vec![];
+    while let Some(indexed) = take_while.next() {
+        seen.push(*indexed.value);
+    }
+    assert_eq!(seen, [0, 2]);
+
+    let mut skip = evens().skip(1);
+    let mut seen = vec![];
+    while let Some(indexed) = skip.next() {
+        seen.push((indexed.index, *indexed.value));
+    }
+    assert_eq!(seen, [(1, 2), (3, 4)]);
+
+    let mut skip_while = evens().skip_while(|&value| value < 2);
+    let mut seen = vec![];
+    while let Some(indexed) = skip_while.next() {
+        seen.push(*indexed.value);
+    }
+    assert_eq!(seen, [2, 4]);
+
+    let mut chain = evens().chain((6_u8..8).reiterate());
+    let mut seen = vec![];
+    while let Some(indexed) = chain.next() {
+        seen.push(*indexed.value);
+    }
+    assert_eq!(seen, [0, 2, 4, 6, 7]);
+
+    let mut zip = evens().zip((10_u8..16).reiterate());
+    let mut seen = vec![];
+    while let Some((index, (&lhs, &rhs))) = zip.next() {
+        seen.push((index, lhs, rhs));
+    }
+    assert_eq!(seen, [(0, 0, 10), (2, 2, 12), (4, 4, 14)]);
+
+    // Evicted only after the view kept them: the view's own indices skip over the hole.
+    let windowed = || {
+        let mut iter = vec![1_u8, 1, 2, 3, 3, 4].reiterate();
+        iter.set_window(core::num::NonZeroUsize::new(2));
+        iter
+    };
+    let mut dedup = windowed().dedup();
+    assert_eq!(dedup.at(3), Some(&4));
+    dedup.restart();
+    assert_eq!(
+        dedup.next().map(|indexed| (indexed.index, *indexed.value)),
+        Some((3, 4))
+    );
+    let mut filter = windowed().filter(|&value| value != 2);
+    assert_eq!(filter.at(4), Some(&4));
+    filter.restart();
+    assert_eq!(
+        filter.next().map(|indexed| (indexed.index, *indexed.value)),
+        Some((3, 3))
+    );
+    let mut unique = windowed().unique_by_key(|&value| value);
+    assert_eq!(unique.at(3), Some(&4));
+    unique.restart();
+    assert_eq!(
+        unique.next().map(|indexed| (indexed.index, *indexed.value)),
+        Some((3, 4))
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn search_contiguous_bytes() {
+    use crate::{contiguous::reiterate_contiguous, frozen::Frozen, search::find_subsequence};
+    let bytes: Frozen<u8> = (0..100).map(|i| (i % 7) as u8).chain([9, 9, 8]).collect();
+    assert_eq!(bytes.position_cached(6), Some(6));
+    assert!(!bytes.contains(7));
+    assert_eq!(bytes.find_subsequence(&[9, 8]), Some(101));
+    assert_eq!(bytes.find_subsequence(&[8, 9]), None);
+    assert_eq!(bytes.find_subsequence(&[]), Some(0));
+    let wide: Frozen<u16> = (0..40).collect();
+    assert_eq!(wide.find_subsequence(&[33, 34, 35]), Some(33));
+    assert_eq!(find_subsequence(&[1_u8, 2], &[1, 2, 3]), None);
+    let mut stream = reiterate_contiguous([0_u8, 0xFF, 1].into_iter());
+    assert!(!stream.contains(0xFF));
+    assert!(stream.at(1).is_some());
+    assert_eq!(stream.position_cached(0xFF), Some(1));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn hint_next_access_respects_probe() {
+    let mut iter = (0_u32..).reiterate();
+    iter.set_probe(crate::cache::Probe::AtMost(10));
+    iter.hint_next_access(&[3, 50, 7]);
+    assert_eq!(iter.cached_len(), 10);
+    iter.set_probe(crate::cache::Probe::CacheOnly);
+    iter.hint_next_access(&[1_000]);
+    assert_eq!(iter.cached_len(), 10);
+    iter.set_probe(crate::cache::Probe::Exhaust);
+    iter.hint_next_access(&[20, 2]);
+    assert_eq!(iter.cached_len(), 21);
+    assert_eq!(iter.index, 0);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn sorted_view_by_reference() {
+    let mut iter = vec![3_u8, 1, 2, 1].reiterate();
+    let address = iter.at(0).map(|value| value as *const u8);
+    let sorted = iter.sorted_view().unwrap();
+    assert_eq!(sorted.permutation(), [1, 3, 2, 0]);
+    assert_eq!(
+        sorted.at(3).map(|indexed| indexed.value as *const u8),
+        address
+    );
+    assert_eq!(
+        sorted
+            .iter()
+            .map(|indexed| *indexed.value)
+            .collect::<Vec<_>>(),
+        [1, 1, 2, 3]
+    );
+    assert!(sorted.at(4).is_none());
+    let descending = iter.sorted_view_by(|a, b| b.cmp(a)).unwrap();
+    assert_eq!(descending.at(0).map(|indexed| indexed.index), Some(0));
+}
+
+#[test]
+#[cfg(feature = "profiling")]
+fn access_report_summarizes_reads() {
+    let mut iter = (0_u8..10).reiterate();
+    for index in [0, 1, 2, 3, 1, 5, 6] {
+        drop(iter.at(index));
+    }
+    let report = iter.access_report();
+    assert_eq!(report.reads, 7);
+    assert_eq!(report.sequential, 4); // 1, 2, 3, 6
+    assert_eq!(report.rereads, 1); // the second 1
+    assert_eq!(report.max_lookbehind, 2); // 3 back to 1
+    assert!((report.sequential_ratio() - 4.0 / 7.0).abs() < f64::EPSILON);
+    iter.reset_access_report();
+    assert_eq!(iter.access_report().reads, 0);
+}
+
+#[test]
+#[cfg(feature = "profiling")]
+fn auto_tune_locks_in_a_window() {
+    use crate::{cache::Growth, profile::Tuning};
+    let mut iter = (0_u32..100).reiterate();
+    iter.auto_tune(40);
+    for index in 0..20 {
+        drop(iter.at(index));
+        drop(iter.at(index.saturating_sub(1)));
+    }
+    assert_eq!(iter.tuning(), None);
+    drop(iter.at(20));
+    assert_eq!(
+        iter.tuning(),
+        Some(Tuning {
+            eviction_window: Some(4),
+            growth: Growth::Chunk(4),
+        })
+    );
+    for index in 21..50 {
+        drop(iter.at(index));
+    }
+    assert_eq!(iter.at(10), None);
+    assert_eq!(iter.at(46), Some(&46));
+    // Reading all over the place keeps everything.
+    let mut scattered = (0_u32..100).reiterate();
+    scattered.auto_tune(3);
+    for index in [50, 0, 25, 75] {
+        drop(scattered.at(index));
+    }
+    assert_eq!(scattered.tuning(), Some(Tuning::default()));
+    assert_eq!(scattered.at(1), Some(&1));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn binary_search_gallops_lazily() {
+    let mut squares = (0_u64..).map(|x| x * x).reiterate();
+    assert_eq!(
+        squares.binary_search(&144).map(|indexed| indexed.index),
+        Ok(12)
+    );
+    assert!(squares.cached_len() <= 16);
+    assert_eq!(
+        squares.binary_search(&145).map(|indexed| indexed.index),
+        Err(13)
+    );
+    assert_eq!(
+        squares
+            .binary_search_by_key(&0, |&x| x)
+            .map(|indexed| indexed.index),
+        Ok(0)
+    );
+    let mut finite = vec![1_u8, 3, 5].reiterate();
+    assert_eq!(
+        finite.binary_search(&6).map(|indexed| indexed.index),
+        Err(3)
+    );
+    assert_eq!(
+        finite.binary_search(&0).map(|indexed| indexed.index),
+        Err(0)
+    );
+    assert_eq!(finite.binary_search(&5).map(|indexed| indexed.index), Ok(2));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn aggregates_fold_each_prefix_once() {
+    let mut agg = [3_i32, -1, 4, 1, -5, 9]
+        .into_iter()
+        .reiterate()
+        .aggregates();
+    assert_eq!(agg.sum_to(2), Some(&6));
+    assert_eq!(agg.min_to(2), Some(&-1));
+    assert_eq!(agg.max_to(5), Some(&9));
+    assert_eq!(agg.min_to(5), Some(&-5));
+    assert_eq!(agg.sum_range(2..5), Some(0));
+    assert_eq!(agg.sum_range(0..1), Some(3));
+    assert_eq!(agg.sum_range(3..3), None);
+    assert_eq!(agg.sum_to(6), None);
+    agg.index = 4;
+    assert_eq!(agg.next().map(|indexed| indexed.value.sum), Some(2));
+    assert_eq!(agg.into_inner().cached_len(), 6);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn map_into_inner_cursor() {
+    use crate::CursorOnUnwrap;
+    let mut iter = (0_u8..10).reiterate();
+    iter.index = 2;
+    let mut kept = iter.map_values(|&v| v);
+    assert_eq!(kept.next(), Some(2));
+    assert_eq!(kept.next(), Some(3));
+    let iter = kept.into_inner();
+    assert_eq!(iter.index, 4);
+    let mut reverted = iter.cloned().on_unwrap(CursorOnUnwrap::Revert);
+    assert_eq!(reverted.next(), Some((4, 4)));
+    assert_eq!(reverted.into_inner().index, 4);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn back_access_shares_indices() {
+    let mut iter = (0_u16..10).reiterate();
+    assert_eq!(iter.exact_len(), 10);
+    assert_eq!(iter.at_back(0), Some(&9));
+    assert_eq!(iter.at_back(3), Some(&6));
+    assert_eq!(iter.cached_len(), 0);
+    assert_eq!(
+        iter.next_back()
+            .map(|indexed| (indexed.index, *indexed.value)),
+        Some((9, 9))
+    );
+    assert_eq!(iter.at(2), Some(&2));
+    // Walking forward runs into what the back already pulled.
+    for index in 0..10 {
+        assert_eq!(iter.at(index), Some(&(index as u16)));
+    }
+    assert_eq!(iter.at(10), None);
+    assert_eq!(iter.exact_len(), 10);
+    assert_eq!(iter.at_back(10), None);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn back_pulls_still_count_toward_len() {
+    let mut iter = crate::exact::reiterate_exact(0_u16..10);
+    assert_eq!(iter.next_back().map(|indexed| *indexed.value), Some(9));
+    assert_eq!(iter.at_back(2), Some(&7));
+    assert_eq!(iter.size_hint(), (10, Some(10)));
+    assert_eq!(iter.at(4), Some(&4));
+    iter.index = 3;
+    assert_eq!(iter.size_hint(), (7, Some(7)));
+    let mut values = iter.map_values(|&value| value);
+    assert_eq!(values.len(), 7);
+    assert_eq!(values.next(), Some(3));
+    assert_eq!(values.len(), 6);
+    assert_eq!(values.last(), Some(9));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn at_from_end_uses_exact_sizes() {
+    let mut exact = (0_u8..10).reiterate();
+    assert_eq!(exact.at_from_end(0), Some(&9));
+    assert_eq!(exact.at_from_end(9), Some(&0));
+    assert_eq!(exact.at_from_end(10), None);
+    let mut tokens = "let x = 1 ;".split(' ').reiterate();
+    assert_eq!(tokens.at_from_end(1), Some(&"1"));
+    assert_eq!(tokens.at_from_end(0), Some(&";"));
+    let mut sized = (0_u8..10).reiterate();
+    assert_eq!(sized.at_from_end(5), Some(&4));
+    assert_eq!(sized.cached_len(), 5);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "unstable"))]
+fn scoped_consumers_share_one_cache() {
+    let mut iter = (0_u64..100).reiterate();
+    let sums = iter.scope_consumers(4, |cursor| {
+        let worker = cursor.worker();
+        cursor
+            .filter(|indexed| indexed.index % 4 == worker)
+            .map(|indexed| *indexed.value)
+            .sum::<u64>()
+    });
+    assert_eq!(sums.len(), 4);
+    assert_eq!(sums.iter().sum::<u64>(), 4950);
+    assert_eq!(sums.first(), Some(&1200));
+    assert_eq!(iter.cached_len(), 100);
+    assert_eq!(iter.index, 0);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "unstable"))]
+fn work_stealing_claims_every_index_once() {
+    use crate::scoped::WorkStealing;
+    use core::num::NonZeroUsize;
+    let chunk = NonZeroUsize::new(2).unwrap_or(NonZeroUsize::MIN);
+    let work = WorkStealing::new(0..10, 2, chunk);
+    assert_eq!(work.claim(1), Some(5..7));
+    assert_eq!(work.claim(1), Some(7..9));
+    assert_eq!(work.claim(1), Some(9..10));
+    // Worker 1 is done, so it steals the back half of worker 0's share.
+    assert_eq!(work.claim(1), Some(2..4));
+    assert_eq!(work.claim(0), Some(0..2));
+    // And now worker 0 steals back what worker 1 hasn't gotten to.
+    assert_eq!(work.claim(0), Some(4..5));
+    assert_eq!(work.claim(0), None);
+    assert_eq!(work.claim(2), None);
+
+    // Shares near the top of `usize` don't saturate, and the remainder goes one each to the first workers.
+    let huge = WorkStealing::new(0..usize::MAX, 4, chunk);
+    let quarter = usize::MAX / 4;
+    assert_eq!(huge.claim(1), Some(quarter + 1..quarter + 3));
+    assert_eq!(huge.claim(2), Some(2 * quarter + 2..2 * quarter + 4));
+    assert_eq!(huge.claim(3), Some(3 * quarter + 3..3 * quarter + 5));
+    let uneven = WorkStealing::new(0..7, 3, NonZeroUsize::MAX);
+    assert_eq!(uneven.claim(0), Some(0..3));
+    assert_eq!(uneven.claim(1), Some(3..5));
+    assert_eq!(uneven.claim(2), Some(5..7));
+
+    let mut iter = (0_u64..1000).reiterate();
+    let counts = iter.scope_work_stealing(0..1000, 4, chunk, |cursor| {
+        let mut seen = Vec::new();
+        while let Some(range) = cursor.claim() {
+            for index in range {
+                if cursor.worker() == 0 {
+                    std::thread::yield_now(); // uneven costs
+                }
+                seen.extend(cursor.at(index).copied());
+            }
+        }
+        seen
+    });
+    let mut all: Vec<_> = counts.into_iter().flatten().collect();
+    all.sort_unstable();
+    assert_eq!(all, (0..1000).collect::<Vec<_>>());
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "unstable"))]
+fn into_channel_applies_backpressure() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    let pulled = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&pulled);
+    let mut iter = (0_u32..10)
+        .inspect(move |_| drop(counter.fetch_add(1, Ordering::SeqCst)))
+        .reiterate();
+    iter.index = 3;
+    let receiver = iter.into_channel(2);
+    let first = receiver.recv().ok();
+    assert_eq!(first.map(|owned| (owned.index, owned.value)), Some((3, 3)));
+    std::thread::sleep(core::time::Duration::from_millis(50));
+    // Up to 3 to reach the cursor, 1 received, 2 buffered, 1 blocked on send.
+    assert!(pulled.load(Ordering::SeqCst) <= 7);
+    let rest: Vec<_> = receiver.into_iter().map(|owned| owned.value).collect();
+    assert_eq!(rest, [4, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn on_drop_sees_unfinished_sources() {
+    use core::cell::Cell;
+    let drained = Cell::new(0_u8);
+    let mut iter = (0_u8..10)
+        .inspect(|_| drained.set(drained.get() + 1))
+        .reiterate()
+        .drain_on_drop();
+    assert_eq!(iter.at(2), Some(&2));
+    drop(iter);
+    assert_eq!(drained.get(), 10);
+
+    let frontier = Cell::new(None);
+    let mut iter = (0_u8..10).reiterate().on_drop(|at, mut source| {
+        frontier.set(Some((at, source.next())));
+    });
+    assert_eq!(iter.at(3), Some(&3));
+    drop(iter);
+    assert_eq!(
+        frontier.get().map(|(at, next)| (at.index(), next)),
+        Some((4, Some(4)))
+    );
+
+    let called = Cell::new(false);
+    let mut finished = (0_u8..3).reiterate().on_drop(|_, _| called.set(true));
+    let _ = finished.exhaust();
+    drop(finished);
+    let unwrapped = (0_u8..3).reiterate().on_drop(|_, _| called.set(true));
+    drop(unwrapped.into_inner());
+    assert!(!called.get());
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn builder_applies_every_setting() {
+    use crate::{
+        cache::{Growth, Probe},
+        Reiterator,
+    };
+    use core::num::NonZeroUsize;
+    use std::sync::{Arc, Mutex};
+    let blocks = Arc::new(Mutex::new(Vec::new()));
+    let seen = Arc::clone(&blocks);
+    let mut iter = Reiterator::builder(0_u8..10)
+        .capacity(16)
+        .growth(Growth::Chunk(4))
+        .probe(Probe::AtMost(2))
+        .step_by(NonZeroUsize::new(2).unwrap_or(NonZeroUsize::MIN))
+        .subscribe(3, move |block| {
+            if let Ok(mut seen) = seen.lock() {
+                seen.push(block.map(|indexed| *indexed.value).collect::<Vec<_>>());
+            }
+        })
+        .build();
+    assert!(iter.capacity() >= 16);
+    assert_eq!(iter.next().map(|indexed| *indexed.value), Some(0));
+    assert_eq!(iter.next().map(|indexed| *indexed.value), Some(2));
+    iter.index = 9;
+    assert!(iter.get().is_none()); // probe gives up
+    assert_eq!(iter.at(5), Some(&5));
+    assert_eq!(
+        blocks.lock().map(|blocks| blocks.clone()).ok(),
+        Some(vec![vec![0, 1, 2], vec![3, 4, 5]])
+    );
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn clone_from_reuses_storage() {
+    let mut source = (0_u32..).step_by(3).reiterate();
+    let mut copy = source.clone();
+    drop(source.at(99));
+    copy.clone_from(&source);
+    assert_eq!(copy.cached_len(), 100);
+    let capacity = copy.capacity();
+    let first: *const u32 = copy.at(0).map_or(core::ptr::null(), |first| first);
+    source.index = 7;
+    copy.clone_from(&source);
+    assert_eq!(copy.capacity(), capacity);
+    assert_eq!(copy.at(0).map(|first| -> *const u32 { first }), Some(first));
+    assert_eq!(copy.get().map(|indexed| *indexed.value), Some(21));
+    assert_eq!(copy.at(150), Some(&450));
+    assert_eq!(source.cached_len(), 100);
+    let mut other = (0_u32..).step_by(3).reiterate();
+    other.copy_state_from(&source);
+    assert_eq!(other.index, 7);
+    assert_eq!(other.cached_len(), 100);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn max_cached_caps_unbounded_sources() {
+    use crate::{
+        cache::{CacheFull, Overflow},
+        Reiterator,
+    };
+    let mut refusing = (0_u64..).reiterate();
+    refusing.set_max_cached(8, Overflow::Refuse);
+    assert_eq!(refusing.checked_at(7), Ok(Some(&7)));
+    assert_eq!(
+        refusing.checked_at(usize::MAX),
+        Err(CacheFull {
+            max_cached: 8,
+            index: usize::MAX,
+        })
+    );
+    assert_eq!(refusing.cached_len(), 8);
+    refusing.retain_cached(|&value| value % 2 == 0);
+    assert_eq!(refusing.checked_at(11), Ok(Some(&11)));
+    assert!(refusing.checked_at(12).is_err());
+
+    let mut evicting = Reiterator::builder(0_u64..)
+        .max_cached(4, Overflow::Evict)
+        .build();
+    assert_eq!(evicting.at(100), Some(&100));
+    assert_eq!(evicting.at(96), None);
+    assert_eq!(evicting.at(97), Some(&97));
+    assert_eq!(evicting.at(0), None);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+#[should_panic(expected = "Cache full")]
+fn max_cached_can_panic() {
+    let mut iter = (0_u64..).reiterate();
+    iter.set_max_cached(2, crate::cache::Overflow::Panic);
+    let _ = iter.at(2);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn offsets_translate_bytes_and_elements() {
+    let text = "aé€😀b";
+    let mut chars = text.chars().reiterate().offsets(|&c| c.len_utf8());
+    assert_eq!(chars.byte_offset_of(3), Some(6));
+    assert_eq!(chars.byte_range_of(1), Some(1..3));
+    assert_eq!(chars.element_at_byte(2), Some(1));
+    assert_eq!(chars.element_at_byte(9), Some(3));
+    assert_eq!(chars.element_at_byte(10), Some(4));
+    assert_eq!(chars.element_at_byte(11), None);
+    assert_eq!(chars.byte_offset_of(5), None);
+    assert_eq!(chars.at(4), Some(&'b'));
+    for (index, (offset, _)) in text.char_indices().enumerate() {
+        assert_eq!(chars.byte_offset_of(index), Some(offset));
+        assert_eq!(chars.element_at_byte(offset), Some(index));
+    }
+    assert_eq!(chars.into_inner().cached_len(), 5);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn window_keeps_only_recent_elements() {
+    use crate::{cache::Evicted, Reiterator};
+    use core::num::NonZeroUsize;
+    let mut iter = Reiterator::builder(0_u64..)
+        .window(NonZeroUsize::new(3).unwrap())
+        .build();
+    assert_eq!(iter.at_or_evicted(9), Ok(Some(&9)));
+    assert_eq!(iter.at_or_evicted(7), Ok(Some(&7)));
+    assert_eq!(iter.at_or_evicted(6), Err(Evicted { index: 6 }));
+    assert_eq!(iter.at_or_evicted(0), Err(Evicted { index: 0 }));
+    assert_eq!(iter.at(6), None);
+    assert_eq!(iter.cached_len(), 10);
+
+    let mut finite = (0_u8..10).reiterate();
+    assert_eq!(finite.at(9), Some(&9));
+    finite.set_window(NonZeroUsize::new(2));
+    assert_eq!(finite.at_or_evicted(7), Err(Evicted { index: 7 }));
+    assert_eq!(finite.at_or_evicted(8), Ok(Some(&8)));
+    assert_eq!(finite.at_or_evicted(10), Ok(None));
+    finite.set_window(None);
+    assert_eq!(finite.at_or_evicted(9), Ok(Some(&9)));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn size_hint_only_promises_what_reads_can_reach() {
+    use crate::cache::{Overflow, Probe};
+    let mut budgeted = (0_u8..10).reiterate();
+    assert_eq!(budgeted.at(3), Some(&3));
+    budgeted.set_probe(Probe::CacheOnly);
+    assert_eq!(budgeted.size_hint(), (4, Some(10)));
+    assert_eq!(budgeted.map_values(|&value| value).count(), 4);
+
+    let mut capped = (0_u8..10).reiterate();
+    capped.set_max_cached(6, Overflow::Refuse);
+    assert_eq!(capped.size_hint(), (0, Some(10)));
+    assert_eq!(capped.map_values(|&value| value).count(), 6);
+
+    let mut unlimited = (0_u8..10).reiterate();
+    unlimited.set_max_cached(6, Overflow::Evict);
+    assert_eq!(unlimited.size_hint(), (10, Some(10)));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn refusal_is_reported_not_truncated() {
+    use crate::{
+        cache::{CacheFull, Overflow},
+        frozen::FreezeError,
+    };
+    use core::num::NonZeroUsize;
+    let full = CacheFull {
+        max_cached: 4,
+        index: 4,
+    };
+    let mut iter = (0_u8..10).reiterate();
+    iter.set_max_cached(4, Overflow::Refuse);
+    assert_eq!(iter.exhaust(), Err(full));
+    assert_eq!(iter.count(), Err(full));
+    assert!(iter.last().is_err());
+    assert_eq!(iter.advance_by(6), Err(NonZeroUsize::new(2).unwrap()));
+    assert_eq!(iter.index, 4);
+    assert_eq!(iter.freeze(), Err(FreezeError::Full(full)));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn back_pulls_and_pushes_respect_the_cap() {
+    use crate::cache::Overflow;
+    let mut iter = (0_u8..10).reiterate();
+    iter.set_max_cached(3, Overflow::Refuse);
+    assert_eq!(iter.at(0), Some(&0));
+    assert_eq!(iter.at_back(1), Some(&8));
+    assert_eq!(iter.at_back(2), None);
+    assert_eq!(iter.push_front_of_source(42), Err(42));
+    assert_eq!(iter.at(1), None);
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn back_pulls_and_pushes_respect_the_window() {
+    use crate::cache::Evicted;
+    use core::num::NonZeroUsize;
+    let mut iter = (0_u8..10).reiterate();
+    iter.set_window(NonZeroUsize::new(2));
+    assert_eq!(iter.at(1), Some(&1));
+    assert_eq!(iter.at_back(0), Some(&9));
+    assert_eq!(iter.at_or_evicted(0), Err(Evicted { index: 0 }));
+    assert_eq!(iter.at_back(1), None);
+    assert_eq!(iter.at(2), Some(&2));
+    assert_eq!(iter.at_or_evicted(1), Err(Evicted { index: 1 }));
+    assert_eq!(iter.push_front_of_source(42), Ok(()));
+    assert_eq!(iter.at_or_evicted(2), Err(Evicted { index: 2 }));
+    assert_eq!(iter.at(3), Some(&42));
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn const_accessors_work_in_const_contexts() {
+    use crate::{
+        cache::Cache,
+        indexed::{copy_value, index, value, IndexBase, Indexed},
+        Reiterator,
+    };
+
+    const ITEM: Indexed<'static, u8> = Indexed {
+        index: 4,
+        value: &42,
+    };
+    const INDEX: usize = index(ITEM);
+    const VALUE: &u8 = value(ITEM);
+    const COPIED: u8 = copy_value(ITEM);
+    const REPORTED: usize = IndexBase::One.report(INDEX);
+    const RECOVERED: Option<usize> = IndexBase::One.index(REPORTED);
+
+    const fn progress<I: Iterator>(iter: &Reiterator<I>) -> (usize, usize, usize) {
+        (iter.index(), iter.cached_len(), iter.frontier().index())
+    }
+
+    const fn fresh<I: Iterator>(iter: &Reiterator<I>) -> bool {
+        iter.is_empty() && iter.cached_len() == 0
+    }
+
+    const fn untouched<I: Iterator>(cache: &Cache<I>) -> bool {
+        cache.is_empty() && cache.cached_len() == 0 && cache.frontier().index() == 0
+    }
+
+    assert_eq!(
+        (INDEX, *VALUE, COPIED, REPORTED, RECOVERED),
+        (4, 42, 42, 5, Some(4))
+    );
+
+    let mut iter = (0_u8..10).reiterate();
+    assert!(fresh(&iter));
+    assert_eq!(progress(&iter), (0, 0, 0));
+    assert_eq!(iter.at(3), Some(&3));
+    assert!(!fresh(&iter));
+    let _ = iter.next();
+    assert_eq!(progress(&iter), (1, 4, 4));
+
+    let mut cache = (0_u8..10).cached();
+    assert!(untouched(&cache));
+    assert_eq!(cache.get(2), Some(&2));
+    assert!(!untouched(&cache));
+    assert_eq!(cache.cached_len(), 3);
+}
+
 quickcheck::quickcheck! {
+    #[cfg(feature = "unstable")]
+    fn prop_binary_search_matches_slice(v: Vec<u8>, target: u8) -> bool {
+        let mut sorted = v;
+        sorted.sort_unstable();
+        let expected = sorted.binary_search(&target);
+        let mut iter = sorted.reiterate();
+        match (iter.binary_search(&target), expected) {
+            (Ok(found), Ok(_)) => *found.value == target,
+            (Err(a), Err(b)) => a == b,
+            _ => false,
+        }
+    }
+
     fn prop_cache_range(indices: ::alloc::vec::Vec<u8>) -> bool {
         let mut cache = (0..=u8::MAX).cached();
         indices.into_iter().all(|i| {
@@ -0,0 +1,217 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Test helpers (behind the `testing` feature). See `assert_reiter_eq!`.
+
+use crate::Reiterator;
+use ::alloc::{format, string::String};
+use core::fmt::{Debug, Write};
+
+/// Number of cached elements on either side of a mismatch to print for context.
+const CONTEXT: usize = 2;
+
+/// Compare a whole `Reiterator` (from index zero, regardless of its cursor) against `expected`,
+/// computing only as much as it takes to find a difference.
+/// Returns `None` if they match, or a human-readable description of the first mismatch with nearby cached elements.
+#[inline]
+#[must_use]
+pub fn mismatch<I: Iterator, Expected: IntoIterator>(
+    reiterator: &mut Reiterator<I>,
+    expected: Expected,
+) -> Option<String>
+where
+    I::Item: PartialEq<Expected::Item> + Debug,
+    Expected::Item: Debug,
+{
+    let mut expected = expected.into_iter();
+    let mut index = 0_usize;
+    loop {
+        let rhs = expected.next();
+        let lhs = reiterator.at(index);
+        match (lhs, rhs) {
+            (None, None) => return None,
+            (Some(lhs), Some(rhs)) if *lhs == rhs => {}
+            (lhs, rhs) => {
+                let mut message = format!(
+                    "Reiterator differs from expected at index {index:}: found {lhs:?}, expected {rhs:?}"
+                );
+                if reiterator.cache.is_evicted(index) {
+                    message.push_str(" (evicted)");
+                }
+                message.push_str("\nCached context:");
+                for indexed in reiterator.cache.cached_range(
+                    index.saturating_sub(CONTEXT)..index.saturating_add(CONTEXT).saturating_add(1),
+                ) {
+                    let marker = if indexed.index == index { ">" } else { " " };
+                    let _ = write!(
+                        message,
+                        "\n  {marker} [{}] {:?}",
+                        indexed.index, indexed.value
+                    );
+                }
+                return Some(message);
+            }
+        }
+        index = index.checked_add(1)?;
+    }
+}
+
+/// Write every element (computing all of them) in a canonical text format: one `index: {:?}` line per element,
+/// with `index: <evicted>` for evicted elements. Never returns if the source is infinite.
+/// # Errors
+/// If `writer` does.
+/// # Panics
+/// If `Overflow::Refuse` stops us before the source ends, so the snapshot can't be complete.
+#[inline]
+pub fn write_snapshot<I: Iterator, Writer: Write>(
+    reiterator: &mut Reiterator<I>,
+    writer: &mut Writer,
+) -> core::fmt::Result
+where
+    I::Item: Debug,
+{
+    let len = reiterator.cache.exhaust();
+    reiterator.notify();
+    let len = len.unwrap_or_else(|full| panic!("Cache full: {full}"));
+    for index in 0..len {
+        match reiterator.at(index) {
+            Some(value) => writeln!(writer, "{index:}: {value:?}")?,
+            None => writeln!(writer, "{index:}: <evicted>")?,
+        }
+    }
+    Ok(())
+}
+
+/// `write_snapshot` into a fresh `String`.
+#[inline]
+#[must_use]
+pub fn snapshot<I: Iterator>(reiterator: &mut Reiterator<I>) -> String
+where
+    I::Item: Debug,
+{
+    let mut text = String::new();
+    let _ = write_snapshot(reiterator, &mut text);
+    text
+}
+
+/// What happened when checking a snapshot.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_enums)]
+pub enum SnapshotOutcome {
+    /// The stored snapshot matched.
+    Matched,
+    /// There was no stored snapshot, so we handed a fresh one to `store`.
+    Created,
+}
+
+/// A stored snapshot didn't match.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct SnapshotMismatch {
+    /// Zero-indexed first line that differs.
+    pub line: usize,
+
+    /// That line in the stored snapshot (empty if the stored snapshot ended early).
+    pub expected: String,
+
+    /// That line as we just computed it (empty if we ended early).
+    pub found: String,
+}
+
+impl core::fmt::Display for SnapshotMismatch {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Snapshot mismatch on line {}: expected `{}`, found `{}`",
+            self.line, self.expected, self.found
+        )
+    }
+}
+
+/// Compare a fully computed `Reiterator` against a stored snapshot, if there is one; otherwise, hand a new snapshot to `store`.
+/// Storage is up to you (a file, a map of test names, another snapshot crate, ...).
+/// # Errors
+/// If the stored snapshot doesn't match, describing the first line that differs.
+#[inline]
+pub fn check_snapshot<I: Iterator, Store: FnOnce(&str)>(
+    reiterator: &mut Reiterator<I>,
+    stored: Option<&str>,
+    store: Store,
+) -> Result<SnapshotOutcome, SnapshotMismatch>
+where
+    I::Item: Debug,
+{
+    let fresh = snapshot(reiterator);
+    let Some(stored) = stored else {
+        store(&fresh);
+        return Ok(SnapshotOutcome::Created);
+    };
+    let mut expected = stored.lines();
+    let mut found = fresh.lines();
+    for line in 0.. {
+        match (expected.next(), found.next()) {
+            (None, None) => break,
+            (lhs, rhs) if lhs == rhs => {}
+            (lhs, rhs) => {
+                return Err(SnapshotMismatch {
+                    line,
+                    expected: lhs.unwrap_or_default().into(),
+                    found: rhs.unwrap_or_default().into(),
+                })
+            }
+        }
+    }
+    Ok(SnapshotOutcome::Matched)
+}
+
+/// `check_snapshot` against a file, creating it if it doesn't exist.
+/// # Errors
+/// If the file can't be read or written.
+/// # Panics
+/// If the stored snapshot doesn't match.
+#[cfg(feature = "std")]
+#[inline]
+pub fn assert_snapshot_file<I: Iterator>(
+    reiterator: &mut Reiterator<I>,
+    path: &std::path::Path,
+) -> std::io::Result<SnapshotOutcome>
+where
+    I::Item: Debug,
+{
+    let stored = match std::fs::read_to_string(path) {
+        Ok(stored) => Some(stored),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e),
+    };
+    let mut written = Ok(());
+    let outcome = check_snapshot(reiterator, stored.as_deref(), |fresh| {
+        written = std::fs::write(path, fresh);
+    });
+    written?;
+    match outcome {
+        Ok(outcome) => Ok(outcome),
+        Err(mismatch) => panic!("{mismatch:} (in {path:?})"),
+    }
+}
+
+/// Assert that a `Reiterator` (from index zero, regardless of its cursor) produces exactly the elements of an iterable.
+/// On failure, panics with the first mismatching index and the cached elements around it.
+/// ```rust
+/// use reiterator::{assert_reiter_eq, Reiterate};
+/// let mut iter = (1..4).reiterate();
+/// assert_reiter_eq!(iter, [1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! assert_reiter_eq {
+    ($reiterator:expr, $expected:expr $(,)?) => {
+        if let ::core::option::Option::Some(message) =
+            $crate::testing::mismatch(&mut $reiterator, $expected)
+        {
+            ::core::panic!("{}", message);
+        }
+    };
+}
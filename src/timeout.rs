@@ -0,0 +1,104 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Source wrapper that gives up on a blocking `next` after a fixed amount of time.
+//! The source runs on its own thread and hands values over a channel, so a stuck `next` never blocks the cache.
+
+use crate::Reiterator;
+use std::{sync::mpsc, thread, time::Duration};
+
+/// The source didn't produce a value in time.
+/// Nothing is lost: the value (if any) will still be there the next time you ask.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[allow(clippy::exhaustive_structs)]
+pub struct TimedOut;
+
+impl core::fmt::Display for TimedOut {
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("source timed out")
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+/// Source driven on a background thread with a per-value deadline.
+/// As an ordinary `Iterator`, this blocks indefinitely; use `Reiterator::try_at` to respect the deadline.
+/// Note that the background thread may compute (at most) one value ahead of what's been asked for.
+#[derive(Debug)]
+pub struct Timeout<Item> {
+    /// Values from the background thread.
+    receiver: mpsc::Receiver<Item>,
+
+    /// How long to wait for each value.
+    timeout: Duration,
+}
+
+impl<Item> Timeout<Item> {
+    /// Wait at most the configured timeout for the next value.
+    /// # Errors
+    /// If the source didn't produce anything in time.
+    #[inline]
+    pub fn try_next(&mut self) -> Result<Option<Item>, TimedOut> {
+        match self.receiver.recv_timeout(self.timeout) {
+            Ok(item) => Ok(Some(item)),
+            Err(mpsc::RecvTimeoutError::Timeout) => Err(TimedOut),
+            Err(mpsc::RecvTimeoutError::Disconnected) => Ok(None),
+        }
+    }
+}
+
+impl<Item> Iterator for Timeout<Item> {
+    type Item = Item;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.recv().ok()
+    }
+}
+
+impl<Item> Reiterator<Timeout<Item>> {
+    /// Like `at`, but give up if the source takes longer than its timeout to produce any one value.
+    /// Everything produced before the timeout stays cached.
+    /// # Errors
+    /// If the source didn't produce a value in time.
+    #[inline(always)]
+    pub fn try_at(&mut self, index: usize) -> Result<Option<&Item>, TimedOut> {
+        let pointer = self
+            .cache
+            .try_get_with(index, Timeout::try_next)
+            .map(|cached| cached.map(|item| -> *const Item { item }));
+        self.notify();
+        Ok(pointer?.map(|pointer| {
+            #[allow(unsafe_code)]
+            // SAFETY: Elements are boxed, and `notify` only reads the cache, so nothing moved.
+            unsafe {
+                &*pointer
+            }
+        }))
+    }
+}
+
+/// Move a source onto its own thread so that any single `next` taking longer than `timeout` becomes a `TimedOut` error.
+#[inline]
+#[must_use]
+pub fn with_timeout<I: IntoIterator>(iter: I, timeout: Duration) -> Timeout<I::Item>
+where
+    I::IntoIter: Send + 'static,
+    I::Item: Send + 'static,
+{
+    let (sender, receiver) = mpsc::sync_channel(0);
+    let iter = iter.into_iter();
+    // Detached: the thread ends on its own once the source is exhausted or we hang up.
+    drop(thread::spawn(move || {
+        for item in iter {
+            if sender.send(item).is_err() {
+                break;
+            }
+        }
+    }));
+    Timeout { receiver, timeout }
+}
@@ -0,0 +1,104 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Text as a `Reiterator` over extended grapheme clusters (what a user thinks of as one character), segmented lazily.
+//! Once cluster `k` has been found, jumping back to it (or anywhere before it) is O(1), which is what an editor moving a cursor back and forth needs.
+
+use crate::Reiterator;
+use core::ops::Range;
+use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
+
+/// One extended grapheme cluster and where it sits in the text.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Grapheme<'text> {
+    /// Byte range of this cluster in the original text.
+    pub range: Range<usize>,
+    /// The cluster itself.
+    pub cluster: &'text str,
+}
+
+/// Lazy grapheme segmentation, yielding each cluster with its byte range.
+#[derive(Clone, Debug)]
+pub struct Graphemes<'text> {
+    /// Segmentation so far.
+    iter: GraphemeIndices<'text>,
+}
+
+impl<'text> Iterator for Graphemes<'text> {
+    type Item = Grapheme<'text>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, cluster) = self.iter.next()?;
+        Some(Grapheme {
+            range: start..start.saturating_add(cluster.len()),
+            cluster,
+        })
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl Reiterator<Graphemes<'_>> {
+    /// Index of the cluster containing byte `offset` of the text, segmenting only as far as that byte.
+    /// `None` if `offset` is past the end of the text, if the cluster containing it has been evicted,
+    /// or if we can't compute that far (e.g. `Overflow::Refuse`).
+    #[inline]
+    pub fn cluster_containing(&mut self, offset: usize) -> Option<usize> {
+        let cached = self.cached_len();
+        let Some(last) = cached.checked_sub(1) else {
+            return self.scan_for(offset, 0);
+        };
+        match self.cache.cached(last).map(|grapheme| grapheme.range.end) {
+            Some(end) if offset >= end => self.scan_for(offset, cached),
+            Some(_) => {
+                // Clusters are contiguous, so their ends are sorted: binary search, falling back to a scan if we hit an evicted one.
+                let mut lo = 0;
+                let mut hi = last;
+                while lo < hi {
+                    let mid = lo.wrapping_add(hi.wrapping_sub(lo) / 2);
+                    match self.cache.cached(mid) {
+                        Some(grapheme) if grapheme.range.end <= offset => lo = mid.wrapping_add(1),
+                        Some(_) => hi = mid,
+                        None => return self.scan_for(offset, 0),
+                    }
+                }
+                Some(lo)
+            }
+            None => self.scan_for(offset, 0),
+        }
+    }
+
+    /// Walk forward from cluster `from`, stepping over evicted ones, until one ends past byte `offset`.
+    /// If that one starts past `offset` too, the cluster that held it was evicted.
+    #[inline]
+    fn scan_for(&mut self, offset: usize, from: usize) -> Option<usize> {
+        let mut index = from;
+        loop {
+            if let Some(grapheme) = self.at(index) {
+                if grapheme.range.end > offset {
+                    return (grapheme.range.start <= offset).then_some(index);
+                }
+            } else if !self.cache.is_evicted(index) {
+                return None;
+            }
+            index = index.checked_add(1)?;
+        }
+    }
+}
+
+/// Create a `Reiterator` over the extended grapheme clusters of `text`, segmenting only as far as you look.
+#[inline(always)]
+#[must_use]
+pub fn reiterate_graphemes(text: &str) -> Reiterator<Graphemes<'_>> {
+    Reiterator::new(Graphemes {
+        iter: text.grapheme_indices(true),
+    })
+}
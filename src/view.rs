@@ -0,0 +1,73 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! What every view over a shared cache does the same way: a cursor (`get`/`next`/`restart`) driven by the view's own `at`,
+//! and handing back whatever it wraps. Each view only has to say where its elements are and which of them were evicted.
+
+/// Implement `get`, `next`, and `restart` on a view with a public `index`, an `at(index)`, and an `evicted(index)`
+/// saying whether `at(index)` is `None` only because that element was evicted.
+/// `next` skips evicted elements (their indices are still counted), just like `Reiterator::next`.
+/// Given one item type, elements come out as `Indexed`; given two, as `(index, (lhs, rhs))` pairs.
+macro_rules! cursor {
+    ($item:ty) => {
+        crate::view::cursor!(@impl Indexed<'_, $item>, |index, value| Indexed { index, value });
+    };
+    ($lhs:ty, $rhs:ty) => {
+        crate::view::cursor!(@impl (usize, (&$lhs, &$rhs)), |index, value| (index, value));
+    };
+    (@impl $output:ty, |$index:ident, $value:ident| $wrap:expr) => {
+        /// Return the current element, provided it's in bounds.
+        #[inline(always)]
+        #[must_use]
+        pub fn get(&mut self) -> Option<$output> {
+            let $index = self.index;
+            let $value = self.at($index)?;
+            Some($wrap)
+        }
+
+        /// Like `Iterator::next` but with a dependent lifetime, and in this view's index space.
+        /// Silently skips over evicted elements (their indices are still counted).
+        #[inline]
+        pub fn next(&mut self) -> Option<$output> {
+            loop {
+                let $index = self.index;
+                self.index = $index.checked_add(1)?;
+                if !self.evicted($index) {
+                    let $value = self.at($index)?;
+                    return Some($wrap);
+                }
+            }
+        }
+
+        /// Set the index to zero. Never redoes anything this view has already worked out.
+        #[inline(always)]
+        pub fn restart(&mut self) {
+            self.index = 0;
+        }
+    };
+}
+pub(crate) use cursor;
+
+/// Implement `into_inner`, handing back the `Reiterator` (or both of them) a view wraps.
+macro_rules! into_inner {
+    ($inner:ident: $iter:ty) => {
+        /// Return the underlying `Reiterator`, with everything this view computed still cached.
+        #[inline(always)]
+        #[must_use]
+        pub fn into_inner(self) -> $iter {
+            self.$inner
+        }
+    };
+    ($first:ident: $a:ty, $second:ident: $b:ty) => {
+        /// Return both underlying `Reiterator`s, with everything this view computed still cached.
+        #[inline(always)]
+        #[must_use]
+        pub fn into_inner(self) -> ($a, $b) {
+            (self.$first, self.$second)
+        }
+    };
+}
+pub(crate) use into_inner;
@@ -0,0 +1,57 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Two `Reiterator`s side by side, index-aligned, ending with the shorter one.
+//! Each half keeps its own cache, so both are still independently seekable once you take them back out.
+
+use crate::Reiterator;
+
+/// View pairing up elements with the same index in two `Reiterator`s.
+#[allow(missing_debug_implementations, clippy::partial_pub_fields)]
+pub struct Zip<A: Iterator, B: Iterator> {
+    /// Left half.
+    first: Reiterator<A>,
+
+    /// Right half.
+    second: Reiterator<B>,
+
+    /// Safe to edit! See `Reiterator::index`.
+    pub index: usize,
+}
+
+impl<A: Iterator, B: Iterator> Zip<A, B> {
+    /// Return both elements at the requested index *or compute them if we haven't*, provided it's in bounds on both sides.
+    /// Never computes anything on the right if the left is out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn at(&mut self, index: usize) -> Option<(&A::Item, &B::Item)> {
+        let lhs = self.first.at(index)?;
+        Some((lhs, self.second.at(index)?))
+    }
+
+    /// Whether `at(index)` is `None` only because the element on either side was evicted, so `next` should skip the pair.
+    #[inline]
+    fn evicted(&mut self, index: usize) -> bool {
+        self.first.cache.is_evicted(index) || self.second.cache.is_evicted(index)
+    }
+
+    crate::view::cursor!(A::Item, B::Item);
+
+    crate::view::into_inner!(first: Reiterator<A>, second: Reiterator<B>);
+}
+
+impl<A: Iterator> Reiterator<A> {
+    /// View pairing up this `Reiterator`'s elements with `other`'s, index by index.
+    #[inline(always)]
+    #[must_use]
+    pub const fn zip<B: Iterator>(self, other: Reiterator<B>) -> Zip<A, B> {
+        Zip {
+            first: self,
+            second: other,
+            index: 0,
+        }
+    }
+}